@@ -4,7 +4,10 @@ use core::{fmt, mem};
 
 use bytemuck::{Pod, Zeroable};
 
-use crate::{string::ArrayCString, Address, Error, FromEndian, PointerSize, Process};
+use crate::{
+    string::{self, ArrayCString},
+    Address, Error, FromEndian, PointerSize, Process,
+};
 
 // Reference:
 // https://learn.microsoft.com/en-us/windows/win32/debug/pe-format
@@ -100,9 +103,25 @@ struct OptionalCOFFHeader {
 struct ExportedSymbolsTableDef {
     _unk: [u8; 0x14],
     number_of_functions: u32,
-    _unk_1: u32,
+    number_of_names: u32,
     function_address_array_index: u32,
     function_name_array_index: u32,
+    name_ordinal_array_index: u32,
+}
+
+#[derive(Debug, Copy, Clone, Zeroable, Pod)]
+#[repr(C)]
+struct SectionHeader {
+    name: [u8; 8],
+    virtual_size: u32,
+    virtual_address: u32,
+    size_of_raw_data: u32,
+    pointer_to_raw_data: u32,
+    pointer_to_relocations: u32,
+    pointer_to_linenumbers: u32,
+    number_of_relocations: u16,
+    number_of_linenumbers: u16,
+    characteristics: u32,
 }
 
 /// The machine type (architecture) of a module in a process. An image file can
@@ -285,6 +304,77 @@ fn read_coff_header(process: &Process, module_address: Address) -> Option<(COFFH
     Some((coff_header, coff_header_address))
 }
 
+/// A section of a module (`exe` or `dll`), such as `.text` or `.data`.
+#[derive(Copy, Clone)]
+pub struct Section {
+    /// The name of the section.
+    pub name: ArrayCString<8>,
+    /// The address of the section in the process's memory.
+    pub address: Address,
+    /// The size of the section in the process's memory.
+    pub size: u64,
+    characteristics: u32,
+}
+
+impl Section {
+    const IMAGE_SCN_CNT_CODE: u32 = 0x0000_0020;
+    const IMAGE_SCN_MEM_EXECUTE: u32 = 0x2000_0000;
+
+    /// Checks whether the section contains executable code.
+    pub const fn is_executable(&self) -> bool {
+        self.characteristics & (Self::IMAGE_SCN_CNT_CODE | Self::IMAGE_SCN_MEM_EXECUTE) != 0
+    }
+}
+
+/// Iterates over the sections of a module (`exe` or `dll`) in the given
+/// process. Returns an empty iterator if the headers of the module can't be
+/// read.
+pub fn sections(
+    process: &Process,
+    module_address: impl Into<Address>,
+) -> impl DoubleEndedIterator<Item = Section> + '_ {
+    let module_address: Address = module_address.into();
+
+    let (section_table, number_of_sections) = match read_coff_header(process, module_address) {
+        Some((coff_header, coff_header_address)) => (
+            coff_header_address
+                + mem::size_of::<COFFHeader>() as u64
+                + coff_header.size_of_optional_header.from_le() as u64,
+            coff_header.number_of_sections.from_le(),
+        ),
+        None => (Address::NULL, 0),
+    };
+
+    (0..number_of_sections).filter_map(move |i| {
+        let header = process
+            .read::<SectionHeader>(
+                section_table + (i as u64).wrapping_mul(mem::size_of::<SectionHeader>() as u64),
+            )
+            .ok()?;
+
+        Some(Section {
+            name: bytemuck::cast(header.name),
+            address: module_address + header.virtual_address.from_le(),
+            size: header.virtual_size.from_le() as u64,
+            characteristics: header.characteristics.from_le(),
+        })
+    })
+}
+
+/// Looks up the section of a module (`exe` or `dll`) with the name given,
+/// such as `.text`, and returns its address and size. This can be passed
+/// directly to [`Signature::scan_process_range`](crate::signature::Signature::scan_process_range)
+/// to only scan that section.
+pub fn get_section_range(
+    process: &Process,
+    module_address: impl Into<Address>,
+    name: &str,
+) -> Option<(Address, u64)> {
+    sections(process, module_address)
+        .find(|section| section.name.matches(name))
+        .map(|section| (section.address, section.size))
+}
+
 /// A symbol exported into the current module.
 pub struct Symbol {
     /// The address associated with the current symbol
@@ -310,34 +400,7 @@ pub fn symbols(
     module_address: impl Into<Address>,
 ) -> impl DoubleEndedIterator<Item = Symbol> + '_ {
     let address: Address = module_address.into();
-    let dos_header = process.read::<DOSHeader>(address);
-
-    let is_64_bit = match dos_header {
-        Ok(_) => matches!(
-            MachineType::read(process, address),
-            Some(MachineType::X86_64)
-        ),
-        _ => false,
-    };
-
-    let export_directory = match dos_header {
-        Ok(header) => process
-            .read::<u32>(address + header.e_lfanew + if is_64_bit { 0x88 } else { 0x78 })
-            .ok(),
-        _ => None,
-    };
-
-    let symbols_def = match dos_header {
-        Ok(_) => match export_directory {
-            Some(0) => None,
-            Some(export_dir) => process
-                .read::<ExportedSymbolsTableDef>(address + export_dir)
-                .ok(),
-            _ => None,
-        },
-        _ => None,
-    }
-    .unwrap_or_default();
+    let symbols_def = read_export_directory(process, address).unwrap_or_default();
 
     (0..symbols_def.number_of_functions).filter_map(move |i| {
         Some(Symbol {
@@ -356,3 +419,53 @@ pub fn symbols(
         })
     })
 }
+
+/// Looks up the address of the function that a module (`exe` or `dll`)
+/// exports under the name given. Unlike searching through the
+/// [`symbols`], this resolves the name through the table of names of the
+/// exports, which also works for modules whose exports don't all have a name.
+/// Returns [`None`] if the module doesn't export a function with that name.
+pub fn get_export_address(
+    process: &Process,
+    module_address: impl Into<Address>,
+    name: &str,
+) -> Option<Address> {
+    let address: Address = module_address.into();
+    let symbols_def = read_export_directory(process, address)?;
+
+    let index = (0..symbols_def.number_of_names as u64).find(|&i| {
+        process
+            .read::<u32>(address + symbols_def.function_name_array_index + i.wrapping_mul(4))
+            .is_ok_and(|name_addr| string::c_str_matches(process, address + name_addr, name))
+    })?;
+
+    let ordinal = process
+        .read::<u16>(address + symbols_def.name_ordinal_array_index + index.wrapping_mul(2))
+        .ok()?;
+    if ordinal as u32 >= symbols_def.number_of_functions {
+        return None;
+    }
+
+    let function = process
+        .read::<u32>(address + symbols_def.function_address_array_index + ordinal as u64 * 4)
+        .ok()?;
+    Some(address + function)
+}
+
+fn read_export_directory(process: &Process, address: Address) -> Option<ExportedSymbolsTableDef> {
+    let dos_header = process.read::<DOSHeader>(address).ok()?;
+
+    let is_64_bit = matches!(
+        MachineType::read(process, address),
+        Some(MachineType::X86_64)
+    );
+
+    let export_directory = process
+        .read::<u32>(address + dos_header.e_lfanew + if is_64_bit { 0x88 } else { 0x78 })
+        .ok()
+        .filter(|&val| val != 0)?;
+
+    process
+        .read::<ExportedSymbolsTableDef>(address + export_directory)
+        .ok()
+}
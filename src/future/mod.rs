@@ -146,14 +146,49 @@ impl Future for NextTick {
 #[must_use = "You need to await this future."]
 pub struct Retry<F> {
     f: F,
+    yields_between_attempts: u32,
+    skip: u32,
 }
 
 impl<O: IntoOption, F: FnMut() -> O + Unpin> Future for Retry<F> {
     type Output = O::T;
 
     fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.skip != 0 {
+            self.skip -= 1;
+            return Poll::Pending;
+        }
         match (self.f)().into_option() {
             Some(t) => Poll::Ready(t),
+            None => {
+                // Returning `Pending` already yields once.
+                self.skip = self.yields_between_attempts.saturating_sub(1);
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// A future that retries the given function until it returns [`Some`] or the
+/// maximum amount of tries is exhausted, yielding back to the runtime between
+/// each call.
+#[must_use = "You need to await this future."]
+pub struct RetryTimeout<F> {
+    f: F,
+    remaining: u64,
+}
+
+impl<O: IntoOption, F: FnMut() -> O + Unpin> Future for RetryTimeout<F> {
+    type Output = Option<O::T>;
+
+    fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.remaining == 0 {
+            return Poll::Ready(None);
+        }
+        self.remaining -= 1;
+        match (self.f)().into_option() {
+            Some(t) => Poll::Ready(Some(t)),
+            None if self.remaining == 0 => Poll::Ready(None),
             None => Poll::Pending,
         }
     }
@@ -206,7 +241,58 @@ pub const fn next_tick() -> NextTick {
 /// # }
 /// ```
 pub const fn retry<O: IntoOption, F: FnMut() -> O + Unpin>(f: F) -> Retry<F> {
-    Retry { f }
+    retry_with(f, 1)
+}
+
+/// Retries the given function until it returns [`Some`] or [`Ok`], yielding
+/// back to the runtime `yields_between_attempts` times between each call. This
+/// can be used to space out the attempts, such as when waiting for something
+/// that takes a while to initialize. A value of `1` behaves just like
+/// [`retry`]. A value of `0` is treated as `1`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use asr::{Process, future::retry_with};
+/// # async fn example() {
+/// // Only try attaching every 60 ticks.
+/// let process = retry_with(|| Process::attach("MyGame.exe"), 60).await;
+/// # }
+/// ```
+pub const fn retry_with<O: IntoOption, F: FnMut() -> O + Unpin>(
+    f: F,
+    yields_between_attempts: u32,
+) -> Retry<F> {
+    Retry {
+        f,
+        yields_between_attempts,
+        skip: 0,
+    }
+}
+
+/// Retries the given function until it returns [`Some`] or [`Ok`], yielding
+/// back to the runtime between each call. Unlike [`retry`], this gives up
+/// after the function has been called `max_ticks` times and resolves to
+/// [`None`] in that case.
+///
+/// # Example
+///
+/// ```no_run
+/// # use asr::{Process, future::retry_timeout};
+/// # async fn example() {
+/// if let Some(process) = retry_timeout(|| Process::attach("MyGame.exe"), 600).await {
+///     // Attached within 600 ticks.
+/// }
+/// # }
+/// ```
+pub const fn retry_timeout<O: IntoOption, F: FnMut() -> O + Unpin>(
+    f: F,
+    max_ticks: u64,
+) -> RetryTimeout<F> {
+    RetryTimeout {
+        f,
+        remaining: max_ticks,
+    }
 }
 
 /// A trait for types that can be converted into an [`Option`].
@@ -442,3 +528,116 @@ macro_rules! async_main {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use core::{
+        cell::Cell,
+        future::Future,
+        pin::Pin,
+        ptr,
+        task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+    };
+
+    use super::{retry_timeout, retry_with};
+
+    /// Polls the future until it is ready, just like the runtime does once
+    /// per tick. For every poll, this records whether the function the future
+    /// retries got called during it.
+    fn poll_to_end<F: Future + Unpin>(
+        mut future: F,
+        calls: &Cell<u32>,
+        polls: &mut [bool],
+    ) -> (F::Output, usize) {
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(
+            |_| RawWaker::new(ptr::null(), &VTABLE),
+            |_| {},
+            |_| {},
+            |_| {},
+        );
+        // SAFETY: The vtable doesn't do anything, so it can't violate the
+        // contract of the waker.
+        let waker = unsafe { Waker::from_raw(RawWaker::new(ptr::null(), &VTABLE)) };
+        let mut cx = Context::from_waker(&waker);
+
+        for (i, called) in polls.iter_mut().enumerate() {
+            let before = calls.get();
+            let poll = Pin::new(&mut future).poll(&mut cx);
+            *called = calls.get() != before;
+            if let Poll::Ready(output) = poll {
+                return (output, i + 1);
+            }
+        }
+        panic!("the future didn't finish");
+    }
+
+    #[test]
+    fn retry_with_yields_between_attempts() {
+        let calls = Cell::new(0);
+        let future = retry_with(
+            || {
+                calls.set(calls.get() + 1);
+                (calls.get() == 3).then_some(calls.get())
+            },
+            3,
+        );
+
+        let mut polls = [false; 16];
+        let (output, len) = poll_to_end(future, &calls, &mut polls);
+        assert_eq!(output, 3);
+        // Every failed attempt is followed by exactly 3 polls that return
+        // `Pending`, the first of which is the one making the attempt.
+        assert_eq!(polls[..len], [true, false, false, true, false, false, true],);
+    }
+
+    #[test]
+    fn retry_with_zero_yields_like_retry() {
+        let calls = Cell::new(0);
+        let future = retry_with(
+            || {
+                calls.set(calls.get() + 1);
+                (calls.get() == 3).then_some(())
+            },
+            0,
+        );
+
+        let mut polls = [false; 16];
+        let (_, len) = poll_to_end(future, &calls, &mut polls);
+        assert_eq!(polls[..len], [true, true, true]);
+    }
+
+    #[test]
+    fn retry_timeout_gives_up_after_max_ticks() {
+        let calls = Cell::new(0);
+        let future = retry_timeout(
+            || {
+                calls.set(calls.get() + 1);
+                None::<()>
+            },
+            4,
+        );
+
+        let mut polls = [false; 16];
+        let (output, len) = poll_to_end(future, &calls, &mut polls);
+        assert_eq!(output, None);
+        assert_eq!(len, 4);
+        assert_eq!(calls.get(), 4);
+    }
+
+    #[test]
+    fn retry_timeout_resolves_within_max_ticks() {
+        let calls = Cell::new(0);
+        let future = retry_timeout(
+            || {
+                calls.set(calls.get() + 1);
+                (calls.get() == 2).then_some(calls.get())
+            },
+            4,
+        );
+
+        let mut polls = [false; 16];
+        let (output, len) = poll_to_end(future, &calls, &mut polls);
+        assert_eq!(output, Some(2));
+        assert_eq!(len, 2);
+    }
+}
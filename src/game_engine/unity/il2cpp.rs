@@ -1,17 +1,26 @@
 //! Support for attaching to Unity games that are using the IL2CPP backend.
 
-use core::{array, cell::RefCell, iter};
+use core::{array, cell::RefCell, iter, mem};
 
 use crate::{
-    deep_pointer::DeepPointer, file_format::pe, future::retry, signature::Signature,
-    string::ArrayCString, Address, Address64, Error, PointerSize, Process,
+    deep_pointer::DeepPointer,
+    file_format::pe,
+    future::{retry, retry_timeout},
+    signature::Signature,
+    string::{self, ArrayCString},
+    Address, Address64, Error, PointerSize, Process,
 };
 
 #[cfg(feature = "derive")]
 pub use asr_derive::Il2cppClass as Class;
 use bytemuck::CheckedBitPattern;
 
-const CSTR: usize = 128;
+use super::{
+    AttachError, CollectionKind, DictionaryEntry, TypeKind, DICTIONARY_ENTRIES_FIELD_NAMES,
+    LIST_ITEMS_FIELD_NAMES,
+};
+
+const TYPE_CHUNK: usize = 64;
 
 /// Represents access to a Unity game that is using the IL2CPP backend.
 pub struct Module {
@@ -20,6 +29,7 @@ pub struct Module {
     offsets: &'static Offsets,
     assemblies: Address,
     type_info_definition_table: Address,
+    type_info_signature_index: u8,
 }
 
 impl Module {
@@ -32,74 +42,245 @@ impl Module {
         Self::attach(process, version)
     }
 
+    /// Tries attaching to a Unity game that is using the IL2CPP backend,
+    /// automatically detecting the [IL2CPP version](Version). Unlike
+    /// [`attach_auto_detect`](Self::attach_auto_detect), this reports the
+    /// step that failed. Attaching is also only considered successful once the
+    /// `Assembly-CSharp` [image](Image) can be found.
+    pub fn try_attach_auto_detect(process: &Process) -> Result<Self, AttachError> {
+        let version = detect_version(process).ok_or(AttachError::VersionDetectionFailed)?;
+        let module = Self::try_attach(process, version)?;
+        module
+            .get_default_image(process)
+            .ok_or(AttachError::ImageResolutionFailed)?;
+        Ok(module)
+    }
+
     /// Tries attaching to a Unity game that is using the IL2CPP backend with
     /// the [IL2CPP version](Version) provided. The version needs to be
     /// correct for this function to work. If you don't know the version in
     /// advance, use [`attach_auto_detect`](Self::attach_auto_detect) instead.
     pub fn attach(process: &Process, version: Version) -> Option<Self> {
+        Self::try_attach(process, version).ok()
+    }
+
+    /// Tries attaching to a Unity game that is using the IL2CPP backend with
+    /// the [IL2CPP version](Version) provided, just like
+    /// [`attach`](Self::attach). Unlike that function, this reports why
+    /// attaching failed, which is useful for diagnosing why a specific game
+    /// can't be attached to.
+    pub fn try_attach(process: &Process, version: Version) -> Result<Self, AttachError> {
         let mono_module = {
-            let address = process.get_module_address("GameAssembly.dll").ok()?;
-            let size = pe::read_size_of_image(process, address)? as u64;
-            (address, size)
+            let address = process
+                .get_module_address("GameAssembly.dll")
+                .map_err(|_| AttachError::ModuleNotFound)?;
+            let size =
+                pe::read_size_of_image(process, address).ok_or(AttachError::MemoryReadFailed)?;
+            (address, size as u64)
         };
 
-        let pointer_size = match pe::MachineType::read(process, mono_module.0)? {
+        let pointer_size = match pe::MachineType::read(process, mono_module.0)
+            .ok_or(AttachError::MemoryReadFailed)?
+        {
             pe::MachineType::X86_64 => PointerSize::Bit64,
             _ => PointerSize::Bit32,
         };
 
-        let offsets = Offsets::new(version, pointer_size)?;
+        let offsets =
+            Offsets::new(version, pointer_size).ok_or(AttachError::UnsupportedArchitecture)?;
 
         let assemblies = match pointer_size {
             PointerSize::Bit64 => {
                 const ASSEMBLIES_TRG_SIG: Signature<12> =
                     Signature::new("48 FF C5 80 3C ?? 00 75 ?? 48 8B 1D");
 
-                let addr = ASSEMBLIES_TRG_SIG.scan_process_range(process, mono_module)? + 12;
-                addr + 0x4 + process.read::<i32>(addr).ok()?
+                let addr = ASSEMBLIES_TRG_SIG
+                    .scan_process_range(process, mono_module)
+                    .ok_or(AttachError::SignatureNotFound)?
+                    + 9;
+                process
+                    .read_rip_relative(addr, 3, 7)
+                    .map_err(|_| AttachError::MemoryReadFailed)?
             }
             PointerSize::Bit32 => {
                 const ASSEMBLIES_TRG_SIG: Signature<9> =
                     Signature::new("8A 07 47 84 C0 75 ?? 8B 35");
 
-                let addr = ASSEMBLIES_TRG_SIG.scan_process_range(process, mono_module)? + 9;
-                process.read_pointer(addr, pointer_size).ok()?
+                let addr = ASSEMBLIES_TRG_SIG
+                    .scan_process_range(process, mono_module)
+                    .ok_or(AttachError::SignatureNotFound)?
+                    + 9;
+                process
+                    .read_pointer(addr, pointer_size)
+                    .map_err(|_| AttachError::MemoryReadFailed)?
             }
-            _ => return None,
+            _ => return Err(AttachError::UnsupportedArchitecture),
         };
 
-        let type_info_definition_table = if pointer_size == PointerSize::Bit64 {
-            const TYPE_INFO_DEFINITION_TABLE_TRG_SIG: Signature<10> =
-                Signature::new("48 83 3C ?? 00 75 ?? 8B C? E8");
+        let mut module = Self {
+            pointer_size,
+            version,
+            offsets,
+            assemblies,
+            type_info_definition_table: Address::NULL,
+            type_info_signature_index: 0,
+        };
 
-            let addr = TYPE_INFO_DEFINITION_TABLE_TRG_SIG
-                .scan_process_range(process, mono_module)?
-                .add_signed(-4);
+        // The code that accesses the table differs slightly between builds,
+        // so we try multiple variants of it. The fallbacks only describe the
+        // general shape of the code that looks up a type in the table, so
+        // they may match unrelated code as well. That's why they are only
+        // tried if the primary signature doesn't match at all, and why every
+        // match is checked to actually point to the table before it is
+        // accepted. The index of the variant that matched is kept around for
+        // diagnostics.
+        let (type_info_definition_table, type_info_signature_index) =
+            if pointer_size == PointerSize::Bit64 {
+                // mov rax, [table]; cmp qword ptr [rax+rcx*8], 0; jne
+                const TYPE_INFO_DEFINITION_TABLE_TRG_SIG: Signature<10> =
+                    Signature::new("48 83 3C ?? 00 75 ?? 8B C? E8");
+                // mov rax, [table]; cmp qword ptr [rax+rcx*8], 0; je
+                const TYPE_INFO_DEFINITION_TABLE_TRG_SIG_2: Signature<13> =
+                    Signature::new("48 8B 05 ?? ?? ?? ?? 48 83 3C C8 00 74");
+                // mov rax, [table]; mov rdx, [rax+rcx*8]; test rdx, rdx; jne
+                const TYPE_INFO_DEFINITION_TABLE_TRG_SIG_3: Signature<15> =
+                    Signature::new("48 8B 05 ?? ?? ?? ?? 48 8B 14 C8 48 85 D2 75");
+
+                let primary = TYPE_INFO_DEFINITION_TABLE_TRG_SIG
+                    .scan_all(process, mono_module)
+                    .map(|addr| (addr.add_signed(-4), 0))
+                    .map(|(addr, index)| (process.read_rip_relative(addr, 0, 4), index));
+                let fallbacks = || {
+                    TYPE_INFO_DEFINITION_TABLE_TRG_SIG_2
+                        .scan_all(process, mono_module)
+                        .map(|addr| (addr + 3, 1))
+                        .chain(
+                            TYPE_INFO_DEFINITION_TABLE_TRG_SIG_3
+                                .scan_all(process, mono_module)
+                                .map(|addr| (addr + 3, 2)),
+                        )
+                        .map(|(addr, index)| (process.read_rip_relative(addr, 0, 4), index))
+                };
 
-            process
-                .read_pointer(addr + 0x4 + process.read::<i32>(addr).ok()?, pointer_size)
-                .ok()
-                .filter(|val| !val.is_null())?
-        } else {
-            const TYPE_INFO_DEFINITION_TABLE_TRG_SIG: Signature<10> =
-                Signature::new("C3 A1 ?? ?? ?? ?? 83 3C ?? 00");
+                module.find_type_info_definition_table(process, primary, fallbacks)?
+            } else {
+                // ret; mov eax, [table]; cmp dword ptr [eax+ecx*4], 0
+                const TYPE_INFO_DEFINITION_TABLE_TRG_SIG: Signature<10> =
+                    Signature::new("C3 A1 ?? ?? ?? ?? 83 3C ?? 00");
+                // mov eax, [table]; cmp dword ptr [eax+ecx*4], 0; jne
+                const TYPE_INFO_DEFINITION_TABLE_TRG_SIG_2: Signature<10> =
+                    Signature::new("A1 ?? ?? ?? ?? 83 3C 88 00 75");
+
+                let primary = TYPE_INFO_DEFINITION_TABLE_TRG_SIG
+                    .scan_all(process, mono_module)
+                    .map(|addr| (addr + 2, 0))
+                    .map(|(addr, index)| (process.read_pointer(addr, pointer_size), index));
+                let fallbacks = || {
+                    TYPE_INFO_DEFINITION_TABLE_TRG_SIG_2
+                        .scan_all(process, mono_module)
+                        .map(|addr| (addr + 1, 1))
+                        .map(|(addr, index)| (process.read_pointer(addr, pointer_size), index))
+                };
 
-            let addr =
-                TYPE_INFO_DEFINITION_TABLE_TRG_SIG.scan_process_range(process, mono_module)? + 2;
+                module.find_type_info_definition_table(process, primary, fallbacks)?
+            };
 
-            process
-                .read_pointer(process.read_pointer(addr, pointer_size).ok()?, pointer_size)
-                .ok()
-                .filter(|val| !val.is_null())?
+        module.type_info_definition_table = type_info_definition_table;
+        module.type_info_signature_index = type_info_signature_index;
+        Ok(module)
+    }
+
+    /// Picks the first candidate for the global variable storing the table
+    /// of type definitions that actually points to the table. The fallback
+    /// candidates are only looked for if the primary signature doesn't match
+    /// at all. A signature matching, but none of its candidates pointing to a
+    /// valid table, most likely means that IL2CPP isn't fully initialized
+    /// yet. In that case we fail right away, so retrying the attach doesn't
+    /// scan the whole module for the fallbacks every time.
+    fn find_type_info_definition_table<F: Iterator<Item = (Result<Address, Error>, u8)>>(
+        &self,
+        process: &Process,
+        primary: impl Iterator<Item = (Result<Address, Error>, u8)>,
+        fallbacks: impl FnOnce() -> F,
+    ) -> Result<(Address, u8), AttachError> {
+        let pick = |(variable, index): (Result<Address, Error>, u8)| {
+            let table = variable
+                .and_then(|variable| process.read_pointer(variable, self.pointer_size))
+                .ok()?;
+            self.is_type_info_definition_table(process, table)
+                .then_some((table, index))
         };
 
-        Some(Self {
-            pointer_size,
-            version,
-            offsets,
-            assemblies,
-            type_info_definition_table,
-        })
+        let mut primary = primary.peekable();
+        if primary.peek().is_some() {
+            return primary.find_map(pick).ok_or(AttachError::AttachFailed);
+        }
+
+        let mut fallbacks = fallbacks().peekable();
+        if fallbacks.peek().is_some() {
+            return fallbacks.find_map(pick).ok_or(AttachError::AttachFailed);
+        }
+
+        Err(AttachError::SignatureNotFound)
+    }
+
+    /// Checks whether the address given points to the table of type
+    /// definitions. Every image knows the index of its first type in the
+    /// table. The table gets filled in lazily, so for every image, the first
+    /// class that is set up already among its first types needs to belong to
+    /// that image. At least one image needs to have such a class, which is
+    /// the case for `mscorlib`, whose types get set up early on. Other tables
+    /// of classes aren't indexed the same way, so they are rejected.
+    fn is_type_info_definition_table(&self, process: &Process, table: Address) -> bool {
+        if table.is_null() {
+            return false;
+        }
+
+        let mut any_class = false;
+
+        for image in self
+            .assemblies(process)
+            .filter_map(|assembly| assembly.get_image(process, self))
+        {
+            let (Some(type_start), Ok(type_count)) = (
+                image.get_type_start(process, self),
+                process.read::<u32>(image.image + self.offsets.monoimage_typecount),
+            ) else {
+                continue;
+            };
+
+            let len = (type_count as usize).min(TYPE_CHUNK);
+            let mut classes = [Address::NULL; TYPE_CHUNK];
+            if process
+                .read_pointers_into(
+                    table + type_start as u64 * self.size_of_ptr(),
+                    self.pointer_size,
+                    &mut classes[..len],
+                )
+                .is_err()
+            {
+                return false;
+            }
+
+            if let Some(&class) = classes[..len].iter().find(|class| !class.is_null()) {
+                if !(Class { class }).is_in_image(process, self, image.image) {
+                    return false;
+                }
+                any_class = true;
+            }
+        }
+
+        any_class
+    }
+
+    /// Returns which of the known signatures was used to find the table of
+    /// type definitions while attaching. `0` is the primary signature, while
+    /// higher values refer to the fallback signatures that get tried in
+    /// sequence if the primary one doesn't match. This is meant for
+    /// diagnostics, such as reporting the variant of the code that a game's
+    /// build is using when attaching breaks after an update.
+    pub const fn type_info_signature_index(&self) -> u8 {
+        self.type_info_signature_index
     }
 
     fn assemblies<'a>(
@@ -143,14 +324,65 @@ impl Module {
     /// for this function that accesses the `Assembly-CSharp` [image](Image).
     pub fn get_image(&self, process: &Process, assembly_name: &str) -> Option<Image> {
         self.assemblies(process)
-            .find(|assembly| {
-                assembly
-                    .get_name::<CSTR>(process, self)
-                    .is_ok_and(|name| name.matches(assembly_name))
-            })?
+            .find(|assembly| assembly.name_matches(process, self, assembly_name))?
             .get_image(process, self)
     }
 
+    /// Looks for the first of the specified binary [images](Image) that can
+    /// be found inside the target process. The names are tried in order. This
+    /// is useful if the name of an assembly differs between builds of a game.
+    pub fn get_image_any(&self, process: &Process, assembly_names: &[&str]) -> Option<Image> {
+        assembly_names
+            .iter()
+            .find_map(|assembly_name| self.get_image(process, assembly_name))
+    }
+
+    /// Tries attaching to a Unity game that is using the IL2CPP backend
+    /// with the [IL2CPP version](Version) provided, just like [`attach`](Self::attach).
+    /// Additionally this verifies that the `Assembly-CSharp`
+    /// [image](Image) can be found, so clearly bogus pointers are rejected
+    /// upfront, instead of causing failures later on. This also returns
+    /// [`None`] while the game is still loading its assemblies.
+    pub fn attach_verified(process: &Process, version: Version) -> Option<Self> {
+        let module = Self::attach(process, version)?;
+        module.get_default_image(process)?;
+        Some(module)
+    }
+
+    /// Tries to find the [.NET class](struct@Class) with the specified
+    /// namespace and name in the [image](Image) of the assembly specified.
+    /// This is the most precise way to look up a class, which is useful if
+    /// multiple assemblies, such as plugins, define classes with the same
+    /// namespace and name.
+    pub fn get_class_in(
+        &self,
+        process: &Process,
+        assembly_name: &str,
+        namespace: &str,
+        class_name: &str,
+    ) -> Option<Class> {
+        self.get_image(process, assembly_name)?
+            .get_class_in_namespace(process, self, namespace, class_name)
+    }
+
+    /// Searches the [images](Image) of all the loaded assemblies for the
+    /// [.NET class](struct@Class) with the specified namespace and name. The
+    /// first class that is found is returned together with the
+    /// [image](Image) that it is part of. If you know the assembly that the
+    /// class is part of, use [`get_class_in`](Self::get_class_in) instead.
+    pub fn find_class(
+        &self,
+        process: &Process,
+        namespace: &str,
+        class_name: &str,
+    ) -> Option<(Image, Class)> {
+        self.assemblies(process).find_map(|assembly| {
+            let image = assembly.get_image(process, self)?;
+            let class = image.get_class_in_namespace(process, self, namespace, class_name)?;
+            Some((image, class))
+        })
+    }
+
     /// Looks for the `Assembly-CSharp` binary [image](Image) inside the target
     /// process. An [image](Image) is a .NET DLL that is loaded
     /// by the game. The `Assembly-CSharp` [image](Image) is the main
@@ -173,6 +405,19 @@ impl Module {
         retry(|| Self::attach_auto_detect(process)).await
     }
 
+    /// Attaches to a Unity game that is using the IL2CPP backend, automatically
+    /// detecting the [IL2CPP version](Version) on every try. This is the
+    /// `await`able version of the [`attach_auto_detect`](Self::attach_auto_detect)
+    /// function, yielding back to the runtime between each try. Unlike
+    /// [`wait_attach_auto_detect`](Self::wait_attach_auto_detect), this gives
+    /// up after `max_ticks` tries and returns [`None`] in that case.
+    pub async fn wait_attach_auto_detect_timeout(
+        process: &Process,
+        max_ticks: u64,
+    ) -> Option<Module> {
+        retry_timeout(|| Self::attach_auto_detect(process), max_ticks).await
+    }
+
     /// Attaches to a Unity game that is using the IL2CPP backend with the
     /// [IL2CPP version](Version) provided. The version needs to be correct
     /// for this function to work. If you don't know the version in advance, use
@@ -184,6 +429,30 @@ impl Module {
         retry(|| Self::attach(process, version)).await
     }
 
+    /// Attaches to a Unity game with the [IL2CPP version](Version) provided. This is the
+    /// `await`able version of the [`attach`](Self::attach) function, yielding
+    /// back to the runtime between each try. Unlike
+    /// [`wait_attach`](Self::wait_attach), this gives up after `max_ticks`
+    /// tries and returns [`None`] in that case.
+    pub async fn wait_attach_timeout(
+        process: &Process,
+        version: Version,
+        max_ticks: u64,
+    ) -> Option<Self> {
+        retry_timeout(|| Self::attach(process, version), max_ticks).await
+    }
+
+    /// Attaches to a Unity game that is using the IL2CPP backend with the
+    /// [IL2CPP version](Version) provided and verifies that the `Assembly-CSharp`
+    /// [image](Image) can be found.
+    ///
+    /// This is the `await`able version of the
+    /// [`attach_verified`](Self::attach_verified) function, yielding back to
+    /// the runtime between each try.
+    pub async fn wait_attach_verified(process: &Process, version: Version) -> Module {
+        retry(|| Self::attach_verified(process, version)).await
+    }
+
     /// Looks for the specified binary [image](Image) inside the target process.
     /// An [image](Image) is a .NET DLL that is loaded
     /// by the game. The `Assembly-CSharp` [image](Image) is the main game
@@ -198,6 +467,28 @@ impl Module {
         retry(|| self.get_image(process, assembly_name)).await
     }
 
+    /// Looks for the specified binary [image](Image) inside the target process.
+    /// This is the `await`able version of the [`get_image`](Self::get_image)
+    /// function, yielding back to the runtime between each try. Unlike
+    /// [`wait_get_image`](Self::wait_get_image), this gives up after
+    /// `max_ticks` tries and returns [`None`] in that case.
+    pub async fn wait_get_image_timeout(
+        &self,
+        process: &Process,
+        assembly_name: &str,
+        max_ticks: u64,
+    ) -> Option<Image> {
+        retry_timeout(|| self.get_image(process, assembly_name), max_ticks).await
+    }
+
+    /// Looks for the first of the specified binary [images](Image) that can
+    /// be found inside the target process. This is the `await`able version of
+    /// the [`get_image_any`](Self::get_image_any) function, yielding back to
+    /// the runtime between each try.
+    pub async fn wait_get_image_any(&self, process: &Process, assembly_names: &[&str]) -> Image {
+        retry(|| self.get_image_any(process, assembly_names)).await
+    }
+
     /// Looks for the `Assembly-CSharp` binary [image](Image) inside the target
     /// process. An [image](Image) is a .NET DLL that
     /// is loaded by the game. The `Assembly-CSharp` [image](Image) is the main
@@ -212,6 +503,311 @@ impl Module {
         retry(|| self.get_default_image(process)).await
     }
 
+    /// Looks for the `Assembly-CSharp` binary [image](Image) inside the target
+    /// process. This is the `await`able version of the
+    /// [`get_default_image`](Self::get_default_image) function, yielding back
+    /// to the runtime between each try. Unlike
+    /// [`wait_get_default_image`](Self::wait_get_default_image), this gives up
+    /// after `max_ticks` tries and returns [`None`] in that case.
+    pub async fn wait_get_default_image_timeout(
+        &self,
+        process: &Process,
+        max_ticks: u64,
+    ) -> Option<Image> {
+        retry_timeout(|| self.get_default_image(process), max_ticks).await
+    }
+
+    /// Returns the [.NET class](struct@Class) of the object stored at the
+    /// address given. This is useful if a field is declared as a base type, but
+    /// the actual object stored in it may be of various derived types.
+    pub fn get_class_of_instance(&self, process: &Process, instance: Address) -> Option<Class> {
+        // The object header starts with a pointer directly to the class.
+        Some(Class {
+            class: process
+                .read_pointer(instance, self.pointer_size)
+                .ok()
+                .filter(|val| !val.is_null())?,
+        })
+    }
+
+    /// Returns the size of the header that every managed object starts with.
+    /// The fields of an instance of a class follow the header. In IL2CPP this
+    /// is the `Il2CppObject` struct (`il2cpp-object-internals.h`), which
+    /// consists of the pointer to the class and the pointer to the monitor,
+    /// so it is the size of 2 pointers for all versions.
+    pub const fn object_header_size(&self) -> u64 {
+        2 * self.size_of_ptr()
+    }
+
+    /// Reads the value of an instance field with the specified name from the
+    /// instance given. The field is looked up on the class of the instance,
+    /// so no [class](struct@Class) needs to be found upfront. Returns
+    /// [`None`] if the field doesn't exist or reading it fails. Nothing is
+    /// cached, so the field is looked up on every call. If the field is read
+    /// repeatedly, look up its offset once instead.
+    pub fn read_field<T: CheckedBitPattern>(
+        &self,
+        process: &Process,
+        instance: Address,
+        field_name: &str,
+    ) -> Option<T> {
+        let offset = self.find_instance_field_offset(process, instance, &[field_name])?;
+        process.read(instance + offset).ok()
+    }
+
+    /// Returns the amount of elements stored in an instance of one of the
+    /// common [collections](CollectionKind) of the .NET class library. The
+    /// field storing the amount is looked up on the class of the instance, so
+    /// this works regardless of the layout of the class.
+    pub fn collection_count(
+        &self,
+        process: &Process,
+        instance: Address,
+        kind: CollectionKind,
+    ) -> Option<i32> {
+        let offset =
+            self.find_instance_field_offset(process, instance, kind.count_field_names())?;
+        process.read(instance + offset).ok()
+    }
+
+    /// Returns the address of the first entry of an instance of a
+    /// `System.Collections.Generic.Dictionary<TKey, TValue>`. Each entry
+    /// consists of the hash code, the index of the next entry, the key and
+    /// the value. The entries are stored consecutively, with
+    /// [`dictionary_entry_stride`](Self::dictionary_entry_stride) bytes
+    /// between them. Entries that got removed are not cleared, so they are
+    /// still part of the array.
+    pub fn dictionary_entries_address(
+        &self,
+        process: &Process,
+        dictionary_instance: Address,
+    ) -> Option<Address> {
+        let entries = self.read_dictionary_entries(process, dictionary_instance)?;
+        Some(self.array_elements_address(entries))
+    }
+
+    /// Returns the size of a single entry of an instance of a
+    /// `System.Collections.Generic.Dictionary<TKey, TValue>`. This depends on
+    /// the types of the keys and values.
+    pub fn dictionary_entry_stride(
+        &self,
+        process: &Process,
+        dictionary_instance: Address,
+    ) -> Option<u64> {
+        let entries = self.read_dictionary_entries(process, dictionary_instance)?;
+        let entry_class = self
+            .get_class_of_instance(process, entries)?
+            .get_element_class(process, self)?;
+        self.value_type_size(process, entry_class)
+    }
+
+    /// Returns the size of the value type given, when it is stored inline,
+    /// such as in an array. The instance size of a value type includes the
+    /// object header, even though it isn't stored in that case.
+    fn value_type_size(&self, process: &Process, class: Class) -> Option<u64> {
+        (class.get_instance_size(process, self)? as u64)
+            .checked_sub(self.object_header_size())
+            .filter(|&size| size != 0)
+    }
+
+    /// Iterates over the entries of an instance of a
+    /// `System.Collections.Generic.Dictionary<TKey, TValue>`. Entries that got
+    /// removed are still part of the array of entries, but they are skipped.
+    /// The offsets of the key and the value within an entry are looked up on
+    /// the class of the entries, so this works for any types of keys and
+    /// values.
+    pub fn dictionary_entries<'a>(
+        &'a self,
+        process: &'a Process,
+        dictionary_instance: Address,
+    ) -> Option<impl Iterator<Item = DictionaryEntry> + 'a> {
+        let entries = self.read_dictionary_entries(process, dictionary_instance)?;
+        let entry_class = self
+            .get_class_of_instance(process, entries)?
+            .get_element_class(process, self)?;
+
+        // The entries are value types, so the offsets of their fields and
+        // their instance size include the object header, even though it isn't
+        // stored in the array.
+        let header_size = self.object_header_size();
+        let field_offset = |name| {
+            (entry_class.get_field_offset(process, self, name)? as u64).checked_sub(header_size)
+        };
+        let key_offset = field_offset("key")?;
+        let value_offset = field_offset("value")?;
+        let stride = self.value_type_size(process, entry_class)?;
+
+        // The count includes the entries that got removed, but never exceeds
+        // the length of the array.
+        let count =
+            self.collection_count(process, dictionary_instance, CollectionKind::Dictionary)?;
+        let count = (count.max(0) as u32).min(self.array_len(process, entries)?);
+        let first_entry = self.array_elements_address(entries);
+
+        Some(
+            (0..count as u64)
+                .map(move |index| first_entry + index * stride)
+                // Removed entries have their hash code set to -1.
+                .filter(move |&entry| process.read::<i32>(entry).is_ok_and(|hash| hash >= 0))
+                .map(move |entry| DictionaryEntry {
+                    key: entry + key_offset,
+                    value: entry + value_offset,
+                }),
+        )
+    }
+
+    fn read_dictionary_entries(
+        &self,
+        process: &Process,
+        dictionary_instance: Address,
+    ) -> Option<Address> {
+        let offset = self.find_instance_field_offset(
+            process,
+            dictionary_instance,
+            &DICTIONARY_ENTRIES_FIELD_NAMES,
+        )?;
+        process
+            .read_pointer(dictionary_instance + offset, self.pointer_size)
+            .ok()
+            .filter(|val| !val.is_null())
+    }
+
+    fn find_instance_field_offset(
+        &self,
+        process: &Process,
+        instance: Address,
+        field_names: &[&str],
+    ) -> Option<u32> {
+        let class = self.get_class_of_instance(process, instance)?;
+        field_names
+            .iter()
+            .find_map(|name| class.get_field_offset(process, self, name))
+    }
+
+    /// Returns the length of a .NET array, such as the value of a field of
+    /// type `int[]`.
+    pub fn array_len(&self, process: &Process, array: Address) -> Option<u32> {
+        // The length follows the object header and the bounds.
+        process
+            .read::<u32>(array + self.object_header_size() + self.size_of_ptr())
+            .ok()
+    }
+
+    /// Returns the address of the first element of a .NET array. The elements
+    /// are stored consecutively, with reference types being stored as
+    /// pointers.
+    pub fn array_elements_address(&self, array: Address) -> Address {
+        // The elements follow the object header, the bounds and the length.
+        array + self.object_header_size() + 2 * self.size_of_ptr()
+    }
+
+    /// Reads the element at the index given from a .NET array. Returns
+    /// [`None`] if the index is out of bounds or reading the element fails.
+    /// The elements are spaced apart by the element size of the array's
+    /// class, so [`None`] is also returned if the size of `T` doesn't match
+    /// it. For reference types the elements are pointers, so
+    /// [`Address64`](crate::Address64) or [`Address32`](crate::Address32)
+    /// need to be used as the type of the elements.
+    pub fn read_array_element<T: CheckedBitPattern>(
+        &self,
+        process: &Process,
+        array: Address,
+        index: u32,
+    ) -> Option<T> {
+        if index >= self.array_len(process, array)? {
+            return None;
+        }
+        let element_size = self
+            .get_class_of_instance(process, array)?
+            .get_element_size(process, self)?;
+        if element_size as usize != mem::size_of::<T>() {
+            return None;
+        }
+        let offset = (index as u64).checked_mul(element_size as u64)?;
+        process
+            .read(self.array_elements_address(array) + offset)
+            .ok()
+    }
+
+    /// Returns the amount of elements stored in an instance of a
+    /// `System.Collections.Generic.List<T>`.
+    pub fn list_len(&self, process: &Process, list_instance: Address) -> Option<u32> {
+        self.collection_count(process, list_instance, CollectionKind::List)
+            .and_then(|len| u32::try_from(len).ok())
+    }
+
+    /// Returns the address of the first element stored in an instance of a
+    /// `System.Collections.Generic.List<T>`. The elements are stored
+    /// consecutively, with reference types being stored as pointers.
+    pub fn list_items_address(&self, process: &Process, list_instance: Address) -> Option<Address> {
+        let offset =
+            self.find_instance_field_offset(process, list_instance, &LIST_ITEMS_FIELD_NAMES)?;
+        let items = process
+            .read_pointer(list_instance + offset, self.pointer_size)
+            .ok()
+            .filter(|val| !val.is_null())?;
+        Some(self.array_elements_address(items))
+    }
+
+    /// Reads the element at the index given from an instance of a
+    /// `System.Collections.Generic.List<T>`. Returns [`None`] if the index is
+    /// out of bounds or reading the element fails. For reference types the
+    /// elements are pointers, so [`Address64`](crate::Address64) or
+    /// [`Address32`](crate::Address32) need to be used as the type of the
+    /// elements.
+    pub fn read_list_element<T: CheckedBitPattern>(
+        &self,
+        process: &Process,
+        list_instance: Address,
+        index: u32,
+    ) -> Option<T> {
+        if index >= self.list_len(process, list_instance)? {
+            return None;
+        }
+        let offset = (index as u64).checked_mul(mem::size_of::<T>() as u64)?;
+        process
+            .read(self.list_items_address(process, list_instance)? + offset)
+            .ok()
+    }
+
+    /// Reads all the elements stored in an instance of a
+    /// `System.Collections.Generic.List<T>`. For reference types the elements
+    /// are pointers, so [`Address64`](crate::Address64) or
+    /// [`Address32`](crate::Address32) need to be used as the type of the
+    /// elements.
+    #[cfg(feature = "alloc")]
+    pub fn read_list<T: CheckedBitPattern>(
+        &self,
+        process: &Process,
+        list_instance: Address,
+    ) -> Option<alloc::vec::Vec<T>> {
+        let len = self.list_len(process, list_instance)?;
+        if len == 0 {
+            return Some(alloc::vec::Vec::new());
+        }
+        let items = self.list_items_address(process, list_instance)?;
+        process.read_vec(items, len as usize).ok()
+    }
+
+    /// Returns the [IL2CPP version](Version) that the module got attached with.
+    /// This is either the version that got provided or the one that got
+    /// detected automatically.
+    pub const fn version(&self) -> Version {
+        self.version
+    }
+
+    /// Returns the [pointer size](PointerSize) of the game, which got
+    /// detected from `GameAssembly.dll` while attaching. This is needed for
+    /// following pointer paths manually.
+    pub const fn pointer_size(&self) -> PointerSize {
+        self.pointer_size
+    }
+
+    /// Returns whether the game is a 64-bit process.
+    pub const fn is_64bit(&self) -> bool {
+        matches!(self.pointer_size, PointerSize::Bit64)
+    }
+
     #[inline]
     const fn size_of_ptr(&self) -> u64 {
         self.pointer_size as u64
@@ -224,17 +820,15 @@ struct Assembly {
 }
 
 impl Assembly {
-    fn get_name<const N: usize>(
-        &self,
-        process: &Process,
-        module: &Module,
-    ) -> Result<ArrayCString<N>, Error> {
-        process.read(process.read_pointer(
-            self.assembly
-                + module.offsets.monoassembly_aname
-                + module.offsets.monoassemblyname_name,
-            module.pointer_size,
-        )?)
+    fn name_matches(&self, process: &Process, module: &Module, expected: &str) -> bool {
+        process
+            .read_pointer(
+                self.assembly
+                    + module.offsets.monoassembly_aname
+                    + module.offsets.monoassemblyname_name,
+                module.pointer_size,
+            )
+            .is_ok_and(|name| string::c_str_matches(process, name, expected))
     }
 
     fn get_image(&self, process: &Process, module: &Module) -> Option<Image> {
@@ -257,59 +851,204 @@ pub struct Image {
 }
 
 impl Image {
-    /// Iterates over all [.NET classes](struct@Class) in the image.
+    /// Iterates over all [.NET classes](struct@Class) in the image. The
+    /// classes are read lazily while iterating. Classes without a name, such
+    /// as ones that are not fully set up yet, are skipped.
     pub fn classes<'a>(
         &self,
         process: &'a Process,
         module: &'a Module,
     ) -> impl DoubleEndedIterator<Item = Class> + 'a {
-        let type_count = process.read::<u32>(self.image + module.offsets.monoimage_typecount);
-
-        let metadata_ptr = match type_count {
-            Ok(_) => match module.version {
-                Version::V2020 => process.read_pointer(
-                    self.image + module.offsets.monoimage_metadatahandle,
-                    module.pointer_size,
-                ),
-                _ => Ok(self.image + module.offsets.monoimage_metadatahandle),
-            },
-            _ => Err(Error {}),
-        };
+        self.all_classes(process, module)
+            .filter(|class| class.has_name(process, module))
+    }
 
-        let metadata_handle = match type_count {
-            Ok(0) => None,
-            Ok(_) => match metadata_ptr {
-                Ok(x) => process.read::<u32>(x).ok(),
-                _ => None,
-            },
-            _ => None,
-        };
+    /// Iterates over all [.NET classes](struct@Class) in the image, including
+    /// the ones without a name. The lookups by name don't need to skip those
+    /// upfront, as they fail to match anyway.
+    fn all_classes<'a>(
+        &self,
+        process: &'a Process,
+        module: &'a Module,
+    ) -> impl DoubleEndedIterator<Item = Class> + 'a {
+        let image = self.image;
+        let type_count = process
+            .read::<u32>(self.image + module.offsets.monoimage_typecount)
+            .unwrap_or_default() as usize;
+
+        let ptr = (type_count != 0)
+            .then(|| self.get_type_start(process, module))
+            .flatten()
+            .map(|val| module.type_info_definition_table + val as u64 * module.size_of_ptr());
+
+        // The type definition table is a plain array of pointers, so we read
+        // it in chunks instead of reading each entry on its own.
+        (0..type_count)
+            .step_by(TYPE_CHUNK)
+            .flat_map(move |start| {
+                let len = (type_count - start).min(TYPE_CHUNK);
+                let mut classes = [Address::NULL; TYPE_CHUNK];
+
+                let is_ok = ptr.is_some_and(|ptr| {
+                    process
+                        .read_pointers_into(
+                            ptr + start as u64 * module.size_of_ptr(),
+                            module.pointer_size,
+                            &mut classes[..len],
+                        )
+                        .is_ok()
+                });
 
-        let ptr = metadata_handle.map(|val| {
-            module.type_info_definition_table + (val as u64).wrapping_mul(module.size_of_ptr())
-        });
+                classes.into_iter().take(if is_ok { len } else { 0 })
+            })
+            .filter(|class| !class.is_null())
+            .map(|class| Class { class })
+            // A type count that is off, or a metadata handle that doesn't
+            // belong to the image, makes us read the classes of other images
+            // or garbage. Every class points back to the image it's defined
+            // in, which filters these out.
+            .filter(move |class| class.is_in_image(process, module, image))
+    }
 
-        (0..type_count.unwrap_or_default() as u64).filter_map(move |i| {
-            let class = process
+    /// Reads the metadata handle of the image, which is the index of the
+    /// first type of the image in the global type definition table. It is
+    /// signed, with negative values being invalid, which we must not use as
+    /// an index.
+    fn get_type_start(&self, process: &Process, module: &Module) -> Option<u32> {
+        let handle = match module.version {
+            Version::V2020 | Version::V2021 => process
                 .read_pointer(
-                    ptr? + i.wrapping_mul(module.size_of_ptr()),
+                    self.image + module.offsets.monoimage_metadatahandle,
                     module.pointer_size,
                 )
-                .ok()
-                .filter(|val| !val.is_null())?;
-            Some(Class { class })
-        })
+                .ok()?,
+            _ => self.image + module.offsets.monoimage_metadatahandle,
+        };
+        process
+            .read::<i32>(handle)
+            .ok()
+            .and_then(|val| u32::try_from(val).ok())
+    }
+
+    /// Returns the amount of [.NET classes](struct@Class) stored in the image.
+    /// This can be used as a quick sanity check, as a count of 0 usually means
+    /// that the wrong image got found or the image isn't fully loaded yet.
+    pub fn class_count(&self, process: &Process, module: &Module) -> Option<u32> {
+        process
+            .read(self.image + module.offsets.monoimage_typecount)
+            .ok()
+    }
+
+    /// Returns the [.NET class](struct@Class) located at the given offset from
+    /// the base address of a module. This is the inverse of
+    /// [`Class::rva`](Class::rva) and allows resolving classes that got stored
+    /// by their offset, without having to look them up by their name. The
+    /// offset is only stable within the same build of the game.
+    pub const fn class_at_rva(&self, module_base: Address, rva: u64) -> Option<Class> {
+        match module_base.value().checked_add(rva) {
+            Some(class) => Some(Class {
+                class: Address::new(class),
+            }),
+            None => None,
+        }
     }
 
     /// Tries to find the specified [.NET class](struct@Class) in the image.
     pub fn get_class(&self, process: &Process, module: &Module, class_name: &str) -> Option<Class> {
-        self.classes(process, module).find(|class| {
-            class
-                .get_name::<CSTR>(process, module)
-                .is_ok_and(|name| name.matches(class_name))
+        self.all_classes(process, module)
+            .find(|class| class.name_matches(process, module, class_name))
+    }
+
+    /// Tries to find the [.NET class](struct@Class) with the specified
+    /// metadata token in the image. The token identifies the definition of
+    /// the class within the image. Unlike the names of classes, the tokens
+    /// usually stay the same when a game obfuscates its names.
+    pub fn get_class_by_token(
+        &self,
+        process: &Process,
+        module: &Module,
+        token: u32,
+    ) -> Option<Class> {
+        self.all_classes(process, module)
+            .find(|class| class.get_token(process, module) == Some(token))
+    }
+
+    /// Tries to find the [.NET class](struct@Class) with the specified
+    /// namespace and name in the image. This is useful if multiple classes
+    /// share the same name. Note that IL2CPP only sets up a class once the
+    /// type is first used by the game, so the class may not be found until
+    /// then.
+    pub fn get_class_in_namespace(
+        &self,
+        process: &Process,
+        module: &Module,
+        namespace: &str,
+        class_name: &str,
+    ) -> Option<Class> {
+        self.all_classes(process, module).find(|class| {
+            class.name_matches(process, module, class_name)
+                && class.namespace_matches(process, module, namespace)
         })
     }
 
+    /// Tries to find the [.NET class](struct@Class) with the specified
+    /// namespace and name in the image. This is the `await`able version of
+    /// the [`get_class_in_namespace`](Self::get_class_in_namespace) function,
+    /// yielding back to the runtime between each try.
+    pub async fn wait_get_class_in_namespace(
+        &self,
+        process: &Process,
+        module: &Module,
+        namespace: &str,
+        class_name: &str,
+    ) -> Class {
+        retry(|| self.get_class_in_namespace(process, module, namespace, class_name)).await
+    }
+
+    /// Tries to find the [.NET class](struct@Class) described by the path
+    /// specified in the image. The path is written the way decompilers show
+    /// the names of types, such as `Namespace.ClassName` for a class in a
+    /// namespace and `Outer/Nested` for a class that is nested in another
+    /// class, which can be combined to `Namespace.Outer/Nested`. A path
+    /// without a namespace matches classes in any namespace, just like
+    /// [`get_class`](Self::get_class).
+    pub fn get_class_path(&self, process: &Process, module: &Module, path: &str) -> Option<Class> {
+        let mut segments = path.split('/');
+        let outer = segments.next()?;
+        let (namespace, class_name) = match outer.rsplit_once('.') {
+            Some((namespace, class_name)) => (Some(namespace), class_name),
+            None => (None, outer),
+        };
+
+        let mut class = self.all_classes(process, module).find(|class| {
+            class.name_matches(process, module, class_name)
+                && namespace
+                    .is_none_or(|namespace| class.namespace_matches(process, module, namespace))
+        })?;
+
+        for nested_name in segments {
+            class = self.all_classes(process, module).find(|nested| {
+                nested.name_matches(process, module, nested_name)
+                    && nested.get_declaring_class(process, module) == Some(class)
+            })?;
+        }
+
+        Some(class)
+    }
+
+    /// Tries to find the [.NET class](struct@Class) described by the path
+    /// specified in the image. This is the `await`able version of the
+    /// [`get_class_path`](Self::get_class_path) function, yielding back to
+    /// the runtime between each try.
+    pub async fn wait_get_class_path(
+        &self,
+        process: &Process,
+        module: &Module,
+        path: &str,
+    ) -> Class {
+        retry(|| self.get_class_path(process, module, path)).await
+    }
+
     /// Tries to find the specified [.NET class](struct@Class) in the image.
     /// This is the `await`able version of the [`get_class`](Self::get_class)
     /// function, yielding back to the runtime between each try.
@@ -321,16 +1060,42 @@ impl Image {
     ) -> Class {
         retry(|| self.get_class(process, module, class_name)).await
     }
+
+    /// Tries to find the specified [.NET class](struct@Class) in the image.
+    /// This is the `await`able version of the [`get_class`](Self::get_class)
+    /// function, yielding back to the runtime between each try. Unlike
+    /// [`wait_get_class`](Self::wait_get_class), this gives up after
+    /// `max_ticks` tries and returns [`None`] in that case.
+    pub async fn wait_get_class_timeout(
+        &self,
+        process: &Process,
+        module: &Module,
+        class_name: &str,
+        max_ticks: u64,
+    ) -> Option<Class> {
+        retry_timeout(|| self.get_class(process, module, class_name), max_ticks).await
+    }
 }
 
 /// A .NET class that is part of an [`Image`](Image).
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Class {
     class: Address,
 }
 
 impl Class {
-    fn get_name<const N: usize>(
+    /// Returns the offset of the class from the base address of a module. This
+    /// can be used as a key for storing classes, which can later be resolved
+    /// again via [`Image::class_at_rva`]. Returns [`None`] if the class is
+    /// located before the base address.
+    pub const fn rva(&self, module_base: Address) -> Option<u64> {
+        self.class.value().checked_sub(module_base.value())
+    }
+
+    /// Reads the name of the class. Names that don't fit into `N` bytes
+    /// fail to be read. Use [`get_name_string`](Self::get_name_string) for
+    /// reading names of any length.
+    pub fn get_name<const N: usize>(
         &self,
         process: &Process,
         module: &Module,
@@ -343,7 +1108,45 @@ impl Class {
         //process.read(module.read_pointer(process, self.class + module.offsets.monoclass_name)?)
     }
 
-    fn get_name_space<const N: usize>(
+    fn name_matches(&self, process: &Process, module: &Module, expected: &str) -> bool {
+        process
+            .read_pointer(
+                self.class + module.offsets.monoclass_name,
+                module.pointer_size,
+            )
+            .is_ok_and(|name| string::c_str_matches(process, name, expected))
+    }
+
+    fn has_name(&self, process: &Process, module: &Module) -> bool {
+        process
+            .read_pointer(
+                self.class + module.offsets.monoclass_name,
+                module.pointer_size,
+            )
+            .is_ok_and(|name| !name.is_null())
+    }
+
+    /// Checks whether the class is defined in the image at the address given.
+    /// The image is the first field of every class.
+    fn is_in_image(&self, process: &Process, module: &Module, image: Address) -> bool {
+        process
+            .read_pointer(self.class, module.pointer_size)
+            .is_ok_and(|val| val == image)
+    }
+
+    /// Returns the metadata token of the class, which identifies the
+    /// definition of the class within its image.
+    pub fn get_token(&self, process: &Process, module: &Module) -> Option<u32> {
+        process
+            .read(self.class + module.offsets.monoclass_token)
+            .ok()
+    }
+
+    /// Reads the namespace of the class. Namespaces that don't fit into `N`
+    /// bytes fail to be read. Use
+    /// [`get_namespace_string`](Self::get_namespace_string) for reading
+    /// namespaces of any length.
+    pub fn get_namespace<const N: usize>(
         &self,
         process: &Process,
         module: &Module,
@@ -355,6 +1158,50 @@ impl Class {
         )
     }
 
+    fn namespace_matches(&self, process: &Process, module: &Module, expected: &str) -> bool {
+        process
+            .read_pointer(
+                self.class + module.offsets.monoclass_name_space,
+                module.pointer_size,
+            )
+            .is_ok_and(|name| string::c_str_matches(process, name, expected))
+    }
+
+    /// Reads the name of the class, no matter how long it is. Returns
+    /// [`None`] if the name can't be read.
+    #[cfg(feature = "alloc")]
+    pub fn get_name_string(
+        &self,
+        process: &Process,
+        module: &Module,
+    ) -> Option<alloc::string::String> {
+        let name = process
+            .read_pointer(
+                self.class + module.offsets.monoclass_name,
+                module.pointer_size,
+            )
+            .ok()?;
+        process.read_c_string(name).ok()
+    }
+
+    /// Reads the namespace of the class, no matter how long it is. Returns
+    /// [`None`] if the namespace can't be read. Classes in the global
+    /// namespace have an empty namespace.
+    #[cfg(feature = "alloc")]
+    pub fn get_namespace_string(
+        &self,
+        process: &Process,
+        module: &Module,
+    ) -> Option<alloc::string::String> {
+        let namespace = process
+            .read_pointer(
+                self.class + module.offsets.monoclass_name_space,
+                module.pointer_size,
+            )
+            .ok()?;
+        process.read_c_string(namespace).ok()
+    }
+
     fn fields<'a>(
         &'a self,
         process: &'a Process,
@@ -367,12 +1214,8 @@ impl Class {
             if iter_break {
                 None
             } else if !this_class.class.is_null()
-                && this_class
-                    .get_name::<CSTR>(process, module)
-                    .is_ok_and(|name| !name.matches("Object"))
-                && this_class
-                    .get_name_space::<CSTR>(process, module)
-                    .is_ok_and(|name| !name.matches("UnityEngine"))
+                && !this_class.name_matches(process, module, "Object")
+                && !this_class.namespace_matches(process, module, "UnityEngine")
             {
                 let field_count =
                     process.read::<u16>(this_class.class + module.offsets.monoclass_field_count);
@@ -413,43 +1256,444 @@ impl Class {
     /// Tries to find a field with the specified name in the class. This returns
     /// the offset of the field from the start of an instance of the class. If
     /// it's a static field, the offset will be from the start of the static
-    /// table.
+    /// table. `const` fields are skipped.
     pub fn get_field_offset(
         &self,
         process: &Process,
         module: &Module,
         field_name: &str,
-    ) -> Option<u32> {
-        self.fields(process, module)
-            .find(|field| {
-                field
-                    .get_name::<CSTR>(process, module)
-                    .is_ok_and(|name| name.matches(field_name))
-            })?
-            .get_offset(process, module)
+    ) -> Option<u32> {
+        self.get_field(process, module, field_name)?
+            .get_offset(process, module)
+    }
+
+    /// Tries to find the [kind of the type](TypeKind) of the field with the
+    /// specified name in the class. This tells whether the field stores its
+    /// value inline, such as an `int`, or whether it stores a reference to
+    /// an object that needs to be dereferenced.
+    pub fn get_field_type(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+    ) -> Option<TypeKind> {
+        self.get_field(process, module, field_name)?
+            .get_type_kind(process, module)
+    }
+
+    /// Tries to find the [.NET class](struct@Class) of the type of the field
+    /// with the specified name in the class. For instantiations of generic
+    /// types, such as `List<Foo>`, this is the generic type definition, such
+    /// as `List<T>`. Returns [`None`] for fields of primitive types, such as
+    /// `int`, and for arrays. This is only supported for the
+    /// [`Base`](Version::Base) and [`V2019`](Version::V2019) versions, as
+    /// newer versions refer to the class through the metadata instead.
+    pub fn get_field_class(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+    ) -> Option<Class> {
+        self.get_field(process, module, field_name)?
+            .get_class(process, module)
+    }
+
+    /// Tries to find the offset for a field with the specified name in the
+    /// class, just like [`get_field_offset`](Self::get_field_offset). Unlike
+    /// that function, this also considers `const` fields. Their values are
+    /// part of the metadata of the class rather than stored at an offset, so
+    /// this is rarely needed.
+    pub fn get_field_offset_including_literals(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+    ) -> Option<u32> {
+        self.fields(process, module)
+            .find(|field| field.name_matches(process, module, field_name))?
+            .get_offset(process, module)
+    }
+
+    /// Iterates over the fields of the class and its parent classes, yielding
+    /// the name and offset of each field. This is useful for dumping the
+    /// layout of a class. `const` fields are skipped, as they aren't stored at
+    /// an offset. Static fields are included, with their offset being
+    /// relative to the static data of the class they are declared in.
+    #[cfg(feature = "alloc")]
+    pub fn field_offsets<'a>(
+        &'a self,
+        process: &'a Process,
+        module: &'a Module,
+    ) -> impl Iterator<Item = (alloc::string::String, u32)> + 'a {
+        self.fields(process, module)
+            .filter(|field| !field.is_literal(process, module))
+            .filter_map(|field| {
+                Some((
+                    field.get_name_string(process, module)?,
+                    field.get_offset(process, module)?,
+                ))
+            })
+    }
+
+    /// Tries to find the field with the specified name in the class. `const`
+    /// fields are skipped, so they can't shadow the actual fields of the
+    /// class.
+    fn get_field(&self, process: &Process, module: &Module, field_name: &str) -> Option<Field> {
+        self.fields(process, module).find(|field| {
+            field.name_matches(process, module, field_name) && !field.is_literal(process, module)
+        })
+    }
+
+    /// Reads the value of the instance field with the specified name from an
+    /// instance of the class. This combines looking up the offset of the
+    /// field and reading it. Returns [`None`] if the field doesn't exist or
+    /// reading it fails. Nothing is cached, so the field is looked up on every
+    /// call.
+    pub fn read_field<T: CheckedBitPattern>(
+        &self,
+        process: &Process,
+        module: &Module,
+        instance: Address,
+        field_name: &str,
+    ) -> Option<T> {
+        let offset = self.get_field_offset_for_instance(process, module, instance, field_name)?;
+        process.read(instance + offset).ok()
+    }
+
+    /// Tries to find the offsets for multiple fields with the specified names
+    /// in the class. Unlike calling [`get_field_offset`](Self::get_field_offset)
+    /// for each field, this only walks the fields of the class once. The
+    /// offsets are returned in the same order as the names, with [`None`] for
+    /// each field that couldn't be found.
+    pub fn find_field_offsets<const N: usize>(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_names: [&str; N],
+    ) -> [Option<u32>; N] {
+        let mut offsets = [None; N];
+        self.resolve_field_offsets(process, module, &field_names, &mut offsets);
+        offsets
+    }
+
+    /// Tries to find the offsets for any number of fields with the specified
+    /// names in the class. This is the same as
+    /// [`find_field_offsets`](Self::find_field_offsets), but for when the
+    /// names aren't known at compile time. The fields of the class are only
+    /// walked once, no matter how many names are specified.
+    #[cfg(feature = "alloc")]
+    pub fn find_field_offsets_vec(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_names: &[&str],
+    ) -> alloc::vec::Vec<Option<u32>> {
+        let mut offsets = alloc::vec![None; field_names.len()];
+        self.resolve_field_offsets(process, module, field_names, &mut offsets);
+        offsets
+    }
+
+    /// Walks the fields of the class once, storing the offset of each field
+    /// in `field_names` at the same index in `offsets`.
+    fn resolve_field_offsets(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_names: &[&str],
+        offsets: &mut [Option<u32>],
+    ) {
+        let mut remaining = field_names.len();
+        let mut buf = [0; 128];
+
+        for field in self.fields(process, module) {
+            if remaining == 0 {
+                break;
+            }
+            if field.is_literal(process, module) {
+                continue;
+            }
+            // The name of the field is only read once and then compared
+            // against all the names that are still missing. Names that don't
+            // fit into the buffer are compared in the process instead.
+            let name = field.read_name(process, module, &mut buf);
+            for (offset, &field_name) in offsets.iter_mut().zip(field_names) {
+                let is_match = || match name {
+                    Some(name) => name == field_name.as_bytes(),
+                    None => field.name_matches(process, module, field_name),
+                };
+                if offset.is_none() && is_match() {
+                    *offset = field.get_offset(process, module);
+                    if offset.is_some() {
+                        remaining -= 1;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Tries to find the offsets for multiple fields with the specified names
+    /// in the class, walking the fields of the class only once. The offsets
+    /// are returned in the same order as the names. Returns [`None`] if any of
+    /// the fields couldn't be found.
+    pub fn get_field_offsets<const N: usize>(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_names: [&str; N],
+    ) -> Option<[u32; N]> {
+        let offsets = self.find_field_offsets(process, module, field_names);
+        if offsets.iter().any(Option::is_none) {
+            return None;
+        }
+        Some(offsets.map(Option::unwrap_or_default))
+    }
+
+    fn methods<'a>(
+        &'a self,
+        process: &'a Process,
+        module: &'a Module,
+    ) -> impl Iterator<Item = Method> + 'a {
+        let method_count = process
+            .read::<u16>(self.class + module.offsets.monoclass_method_count)
+            .ok()
+            .filter(|&val| val != 0);
+
+        // IL2CPP sets up the methods of a class lazily, so they may not be
+        // available yet.
+        let methods = match method_count {
+            Some(_) => process
+                .read_pointer(
+                    self.class + module.offsets.monoclass_methods,
+                    module.pointer_size,
+                )
+                .ok()
+                .filter(|val| !val.is_null()),
+            _ => None,
+        };
+
+        (0..method_count.unwrap_or_default() as u64).filter_map(move |i| {
+            Some(Method {
+                method: process
+                    .read_pointer(
+                        methods? + i.wrapping_mul(module.size_of_ptr()),
+                        module.pointer_size,
+                    )
+                    .ok()
+                    .filter(|val| !val.is_null())?,
+            })
+        })
+    }
+
+    /// Tries to find a method with the specified name in the class and returns
+    /// information about it. Only the methods declared by the class itself
+    /// are searched, not the ones of its parent classes. If the method is
+    /// overloaded, the first overload is returned.
+    pub fn get_method_info(
+        &self,
+        process: &Process,
+        module: &Module,
+        method_name: &str,
+    ) -> Option<MethodInfo> {
+        self.methods(process, module)
+            .find(|method| method.name_matches(process, module, method_name))?
+            .get_info(process, module)
+    }
+
+    /// Tries to find a method with the specified name in the class and returns
+    /// the address of its compiled code. Only the methods declared by the
+    /// class itself are searched. If the method is overloaded, the first
+    /// overload is returned. Abstract methods don't have any code, so
+    /// [`None`] is returned for them.
+    pub fn get_method(
+        &self,
+        process: &Process,
+        module: &Module,
+        method_name: &str,
+    ) -> Option<Address> {
+        let method = self
+            .methods(process, module)
+            .find(|method| method.name_matches(process, module, method_name))?;
+
+        // The pointer to the code is the first field of `MethodInfo`.
+        process
+            .read_pointer(method.method, module.pointer_size)
+            .ok()
+            .filter(|val| !val.is_null())
+    }
+
+    /// Tries to find a method with the specified name in the class. This is
+    /// the `await`able version of the [`get_method`](Self::get_method)
+    /// function, yielding back to the runtime between each try.
+    pub async fn wait_get_method(
+        &self,
+        process: &Process,
+        module: &Module,
+        method_name: &str,
+    ) -> Address {
+        retry(|| self.get_method(process, module, method_name)).await
+    }
+
+    /// Tries to find the offset for a field with the specified name for the
+    /// given instance of the class. For generic classes, IL2CPP creates a
+    /// separate class for each instantiation, such as `List<int>` and
+    /// `List<string>`. The offsets of the generic definition found in the
+    /// image don't necessarily match the ones of the instantiation, as the
+    /// size of the type arguments affects the layout. This resolves the field
+    /// on the actual class of the instance, so the offset matches its layout.
+    /// For classes that aren't generic, this is the same as
+    /// [`get_field_offset`](Self::get_field_offset). If the class of the
+    /// instance can't be read, the offset of this class is used as a fallback.
+    pub fn get_field_offset_for_instance(
+        &self,
+        process: &Process,
+        module: &Module,
+        instance: Address,
+        field_name: &str,
+    ) -> Option<u32> {
+        module
+            .get_class_of_instance(process, instance)
+            .and_then(|class| class.get_field_offset(process, module, field_name))
+            .or_else(|| self.get_field_offset(process, module, field_name))
+    }
+
+    /// Returns the size of an instance of the class in bytes, including the
+    /// header of the object.
+    pub fn get_instance_size(&self, process: &Process, module: &Module) -> Option<u32> {
+        process
+            .read::<u32>(self.class + module.offsets.monoclass_instance_size)
+            .ok()
+            .filter(|&val| val != 0)
+    }
+
+    /// Checks whether the offset of an instance field lies within the size of
+    /// an instance of the class. An offset outside of the instance usually
+    /// means that the offset is stale, such as after an update of the game
+    /// changed the layout of the class. This does not apply to static fields.
+    pub fn validate_field_offset(&self, process: &Process, module: &Module, offset: u64) -> bool {
+        self.get_instance_size(process, module)
+            .is_some_and(|size| offset < size as u64)
+    }
+
+    /// Tries to find the offset for an instance field with the specified name
+    /// in the class, just like [`get_field_offset`](Self::get_field_offset).
+    /// Additionally this returns [`None`] if the offset lies outside of an
+    /// instance of the class, which indicates a mismatch in the layout of the
+    /// class. This does not work for static fields.
+    pub fn get_field_offset_checked(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+    ) -> Option<u32> {
+        self.get_field_offset(process, module, field_name)
+            .filter(|&offset| self.validate_field_offset(process, module, offset as u64))
+    }
+
+    /// Tries to find the address of a static instance of the class based on its
+    /// field name. This waits until the field is not null.
+    pub async fn wait_get_static_instance(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+    ) -> Address {
+        retry(self.static_instance_reader(process, module, field_name)).await
+    }
+
+    /// Tries to find the address of a static instance of the class based on its
+    /// field name. This waits until the field is not null. Unlike
+    /// [`wait_get_static_instance`](Self::wait_get_static_instance), this gives
+    /// up after `max_ticks` tries and returns [`None`] in that case.
+    pub async fn wait_get_static_instance_timeout(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+        max_ticks: u64,
+    ) -> Option<Address> {
+        retry_timeout(
+            self.static_instance_reader(process, module, field_name),
+            max_ticks,
+        )
+        .await
+    }
+
+    /// Returns a function reading the instance that is stored in the static
+    /// field with the specified name. The address of the field is only looked
+    /// up until it is found, so retrying the function afterwards only repeats
+    /// the read of the instance.
+    fn static_instance_reader<'a>(
+        &'a self,
+        process: &'a Process,
+        module: &'a Module,
+        field_name: &'a str,
+    ) -> impl FnMut() -> Option<Address> + Unpin + 'a {
+        let mut field_address = None;
+        move || {
+            let address = match field_address {
+                Some(address) => address,
+                None => *field_address
+                    .insert(self.get_static_field_address(process, module, field_name)?),
+            };
+            process
+                .read_pointer(address, module.pointer_size)
+                .ok()
+                .filter(|val| !val.is_null())
+        }
+    }
+
+    /// Tries to find the address of the value of a static field with the
+    /// specified name. Static fields that are declared by a parent class are
+    /// stored in the static table of that class, so the static table of the
+    /// class declaring the field is used.
+    pub fn get_static_field_address(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+    ) -> Option<Address> {
+        let field = self.get_field(process, module, field_name)?;
+
+        let declaring_class = field.get_parent(process, module).unwrap_or(*self);
+        let static_table = declaring_class.get_static_table(process, module)?;
+
+        Some(static_table + field.get_offset(process, module)?)
+    }
+
+    /// Reads the instance that is stored in a static field with the specified
+    /// name, such as the `instance` field of a singleton. Returns [`None`] if
+    /// the field can't be found or if it is still null, which usually means
+    /// that the instance isn't constructed yet.
+    pub fn read_static_instance(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+    ) -> Option<Address> {
+        process
+            .read_pointer(
+                self.get_static_field_address(process, module, field_name)?,
+                module.pointer_size,
+            )
+            .ok()
+            .filter(|val| !val.is_null())
     }
 
-    /// Tries to find the address of a static instance of the class based on its
-    /// field name. This waits until the field is not null.
-    pub async fn wait_get_static_instance(
+    /// Reads the value of the static field with the specified name. This
+    /// combines looking up the field, the static table of the class declaring
+    /// it and reading the value. Returns [`None`] if the field doesn't exist
+    /// or the static table isn't initialized yet, which is the case until the
+    /// game first uses the class, so this can be retried while the game is
+    /// loading.
+    pub fn read_static_field<T: CheckedBitPattern>(
         &self,
         process: &Process,
         module: &Module,
         field_name: &str,
-    ) -> Address {
-        let static_table = self.wait_get_static_table(process, module).await;
-        let field_offset = self
-            .wait_get_field_offset(process, module, field_name)
-            .await;
-        let singleton_location = static_table + field_offset;
-
-        retry(|| {
-            process
-                .read_pointer(singleton_location, module.pointer_size)
-                .ok()
-                .filter(|val| !val.is_null())
-        })
-        .await
+    ) -> Option<T> {
+        process
+            .read(self.get_static_field_address(process, module, field_name)?)
+            .ok()
     }
 
     fn get_static_table_pointer(&self, module: &Module) -> Address {
@@ -465,7 +1709,59 @@ impl Class {
             .filter(|val| !val.is_null())
     }
 
-    /// Tries to find the parent class.
+    /// Tries to find the class that this class is nested in. This returns
+    /// [`None`] if the class is not a nested class.
+    pub fn get_declaring_class(&self, process: &Process, module: &Module) -> Option<Class> {
+        let class = process
+            .read_pointer(
+                self.class + module.offsets.monoclass_declaring_type,
+                module.pointer_size,
+            )
+            .ok()
+            .filter(|val| !val.is_null())?;
+        Some(Class { class })
+    }
+
+    /// Iterates over the classes that are nested in this class, such as the
+    /// compiler generated classes of coroutines. IL2CPP sets up the nested
+    /// classes lazily, so they may not be available yet.
+    pub fn nested_classes<'a>(
+        &self,
+        process: &'a Process,
+        module: &'a Module,
+    ) -> impl Iterator<Item = Class> + 'a {
+        let count = process
+            .read::<u16>(self.class + module.offsets.monoclass_nested_type_count)
+            .ok()
+            .filter(|&val| val != 0);
+
+        let nested_types = match count {
+            Some(_) => process
+                .read_pointer(
+                    self.class + module.offsets.monoclass_nested_types,
+                    module.pointer_size,
+                )
+                .ok()
+                .filter(|val| !val.is_null()),
+            _ => None,
+        };
+
+        (0..count.unwrap_or_default() as u64).filter_map(move |i| {
+            Some(Class {
+                class: process
+                    .read_pointer(
+                        nested_types? + i.wrapping_mul(module.size_of_ptr()),
+                        module.pointer_size,
+                    )
+                    .ok()
+                    .filter(|val| !val.is_null())?,
+            })
+        })
+    }
+
+    /// Tries to find the parent class. This returns [`None`] for the root of
+    /// the class hierarchy, which is `System.Object`, as it doesn't have a
+    /// parent.
     pub fn get_parent(&self, process: &Process, module: &Module) -> Option<Class> {
         let parent = process
             .read_pointer(
@@ -477,6 +1773,104 @@ impl Class {
         Some(Class { class: parent })
     }
 
+    /// Iterates over the parent classes of the class, starting with the
+    /// direct parent and ending with the root of the class hierarchy. Fields
+    /// declared by parent classes are already found by
+    /// [`get_field_offset`](Self::get_field_offset), so this is mostly useful
+    /// for inspecting the class hierarchy.
+    pub fn parents<'a>(
+        &self,
+        process: &'a Process,
+        module: &'a Module,
+    ) -> impl Iterator<Item = Class> + 'a {
+        iter::successors(self.get_parent(process, module), move |class| {
+            class.get_parent(process, module)
+        })
+    }
+
+    /// Checks whether the class derives from the class specified, by walking
+    /// up the chain of parent classes. A class is not considered a subclass
+    /// of itself.
+    pub fn is_subclass_of(&self, process: &Process, module: &Module, other: &Class) -> bool {
+        iter::successors(self.get_parent(process, module), |class| {
+            class.get_parent(process, module)
+        })
+        .any(|class| class == *other)
+    }
+
+    /// Checks whether the class is an `enum`, which is the case if it derives
+    /// from `System.Enum`.
+    pub fn is_enum(&self, process: &Process, module: &Module) -> bool {
+        self.get_parent(process, module).is_some_and(|parent| {
+            parent.name_matches(process, module, "Enum")
+                && parent.namespace_matches(process, module, "System")
+        })
+    }
+
+    /// Iterates over the names of the values of an `enum` class, in the order
+    /// they are declared in. This yields nothing if the class is not an
+    /// `enum`.
+    ///
+    /// Unlike with Mono, where [`enum_values`](super::mono::Class::enum_values)
+    /// also reads the values, only the names are available with IL2CPP. It
+    /// keeps the values solely in the global metadata file, which this crate
+    /// doesn't locate, rather than caching them with the class.
+    #[cfg(feature = "alloc")]
+    pub fn enum_value_names<'a>(
+        &'a self,
+        process: &'a Process,
+        module: &'a Module,
+    ) -> impl Iterator<Item = alloc::string::String> + 'a {
+        self.is_enum(process, module)
+            .then(|| {
+                self.fields(process, module)
+                    .filter(|field| {
+                        field.get_parent(process, module) == Some(*self)
+                            && field.is_literal(process, module)
+                    })
+                    .filter_map(|field| field.get_name_string(process, module))
+            })
+            .into_iter()
+            .flatten()
+    }
+
+    /// Tries to find the class of the elements of an array class. This
+    /// returns [`None`] if the class is not an array class.
+    pub fn get_element_class(&self, process: &Process, module: &Module) -> Option<Class> {
+        // Other classes may still have an element class, such as enums, which
+        // refer to their underlying type, so the type of the class decides
+        // whether it's an array.
+        process
+            .read::<u8>(
+                self.class + module.offsets.monoclass_byval_arg + module.offsets.il2cpptype_type,
+            )
+            .ok()
+            .and_then(TypeKind::from_raw)
+            .filter(|kind| matches!(kind, TypeKind::SzArray | TypeKind::Array))?;
+
+        let class = process
+            .read_pointer(
+                self.class + module.offsets.monoclass_element_class,
+                module.pointer_size,
+            )
+            .ok()
+            .filter(|val| !val.is_null())?;
+
+        Some(Class { class })
+    }
+
+    /// Returns the size of each element in bytes if the class is an array
+    /// class. For reference types this is the size of a pointer, for value
+    /// types it is the size of the value itself. This returns [`None`] if the
+    /// class is not an array class.
+    pub fn get_element_size(&self, process: &Process, module: &Module) -> Option<u32> {
+        self.get_element_class(process, module)?;
+        process
+            .read::<u32>(self.class + module.offsets.monoclass_element_size)
+            .ok()
+            .filter(|&val| val != 0)
+    }
+
     /// Tries to find a field with the specified name in the class. This returns
     /// the offset of the field from the start of an instance of the class. If
     /// it's a static field, the offset will be from the start of the static
@@ -491,6 +1885,20 @@ impl Class {
         retry(|| self.get_field_offset(process, module, name)).await
     }
 
+    /// Tries to find a field with the specified name in the class. This is the
+    /// `await`able version of the [`get_field_offset`](Self::get_field_offset)
+    /// function. Unlike [`wait_get_field_offset`](Self::wait_get_field_offset),
+    /// this gives up after `max_ticks` tries and returns [`None`] in that case.
+    pub async fn wait_get_field_offset_timeout(
+        &self,
+        process: &Process,
+        module: &Module,
+        name: &str,
+        max_ticks: u64,
+    ) -> Option<u32> {
+        retry_timeout(|| self.get_field_offset(process, module, name), max_ticks).await
+    }
+
     /// Returns the address of the static table of the class. This contains the
     /// values of all the static fields. This is the `await`able version of the
     /// [`get_static_table`](Self::get_static_table) function.
@@ -498,6 +1906,19 @@ impl Class {
         retry(|| self.get_static_table(process, module)).await
     }
 
+    /// Returns the address of the static table of the class. This is the
+    /// `await`able version of the [`get_static_table`](Self::get_static_table)
+    /// function. Unlike [`wait_get_static_table`](Self::wait_get_static_table),
+    /// this gives up after `max_ticks` tries and returns [`None`] in that case.
+    pub async fn wait_get_static_table_timeout(
+        &self,
+        process: &Process,
+        module: &Module,
+        max_ticks: u64,
+    ) -> Option<Address> {
+        retry_timeout(|| self.get_static_table(process, module), max_ticks).await
+    }
+
     /// Tries to find the parent class. This is the `await`able version of the
     /// [`get_parent`](Self::get_parent) function.
     pub async fn wait_get_parent(&self, process: &Process, module: &Module) -> Class {
@@ -511,16 +1932,44 @@ struct Field {
 }
 
 impl Field {
-    fn get_name<const N: usize>(
+    fn name_matches(&self, process: &Process, module: &Module, expected: &str) -> bool {
+        process
+            .read_pointer(
+                self.field + module.offsets.monoclassfield_name,
+                module.pointer_size,
+            )
+            .is_ok_and(|name| string::c_str_matches(process, name, expected))
+    }
+
+    /// Reads the name of the field into the buffer given and returns it
+    /// without its null terminator. Returns [`None`] if the name doesn't fit
+    /// into the buffer.
+    fn read_name<'buf>(
         &self,
         process: &Process,
         module: &Module,
-    ) -> Result<ArrayCString<N>, Error> {
-        process.read_pointer_path(
-            self.field,
-            module.pointer_size,
-            &[module.offsets.monoclassfield_name.into(), 0x0],
-        )
+        buf: &'buf mut [u8],
+    ) -> Option<&'buf [u8]> {
+        let name = process
+            .read_pointer(
+                self.field + module.offsets.monoclassfield_name,
+                module.pointer_size,
+            )
+            .ok()?;
+        let len = process.read_partial(name, buf).ok()?;
+        let name = &buf[..len];
+        Some(&name[..name.iter().position(|&b| b == 0)?])
+    }
+
+    #[cfg(feature = "alloc")]
+    fn get_name_string(&self, process: &Process, module: &Module) -> Option<alloc::string::String> {
+        let name = process
+            .read_pointer(
+                self.field + module.offsets.monoclassfield_name,
+                module.pointer_size,
+            )
+            .ok()?;
+        process.read_c_string(name).ok()
     }
 
     fn get_offset(&self, process: &Process, module: &Module) -> Option<u32> {
@@ -528,6 +1977,347 @@ impl Field {
             .read(self.field + module.offsets.monoclassfield_offset)
             .ok()
     }
+
+    /// Checks whether the field is a `const` field, based on the attributes
+    /// of its type. If they can't be read, the field is not considered to be
+    /// a `const` field.
+    fn is_literal(&self, process: &Process, module: &Module) -> bool {
+        const FIELD_ATTRIBUTE_LITERAL: u16 = 0x40;
+
+        process
+            .read_pointer(
+                self.field + module.offsets.monoclassfield_type,
+                module.pointer_size,
+            )
+            .and_then(|ty| process.read::<u16>(ty + module.offsets.il2cpptype_attrs))
+            .is_ok_and(|attrs| attrs & FIELD_ATTRIBUTE_LITERAL != 0)
+    }
+
+    fn get_type_kind(&self, process: &Process, module: &Module) -> Option<TypeKind> {
+        let ty = process
+            .read_pointer(
+                self.field + module.offsets.monoclassfield_type,
+                module.pointer_size,
+            )
+            .ok()?;
+        TypeKind::from_raw(process.read(ty + module.offsets.il2cpptype_type).ok()?)
+    }
+
+    /// Returns the class of the type of the field. In the versions that are
+    /// supported, types that refer to a class store the index of its
+    /// definition, while instantiations of generic types store a pointer to an
+    /// `Il2CppGenericClass`, which starts with the index of the definition of
+    /// the generic type.
+    fn get_class(&self, process: &Process, module: &Module) -> Option<Class> {
+        if !matches!(module.version, Version::Base | Version::V2019) {
+            return None;
+        }
+
+        let ty = process
+            .read_pointer(
+                self.field + module.offsets.monoclassfield_type,
+                module.pointer_size,
+            )
+            .ok()?;
+        let kind = TypeKind::from_raw(process.read(ty + module.offsets.il2cpptype_type).ok()?)?;
+
+        let index = match kind {
+            TypeKind::Class | TypeKind::ValueType => process.read::<i32>(ty).ok()?,
+            TypeKind::GenericInst => {
+                let generic_class = process
+                    .read_pointer(ty, module.pointer_size)
+                    .ok()
+                    .filter(|val| !val.is_null())?;
+                process.read::<i32>(generic_class).ok()?
+            }
+            _ => return None,
+        };
+        let index = u64::try_from(index).ok()?;
+
+        let class = process
+            .read_pointer(
+                module.type_info_definition_table + index.wrapping_mul(module.size_of_ptr()),
+                module.pointer_size,
+            )
+            .ok()
+            .filter(|val| !val.is_null())?;
+        Some(Class { class })
+    }
+
+    /// Returns the class that declares the field.
+    fn get_parent(&self, process: &Process, module: &Module) -> Option<Class> {
+        Some(Class {
+            class: process
+                .read_pointer(
+                    self.field + module.offsets.monoclassfield_parent,
+                    module.pointer_size,
+                )
+                .ok()
+                .filter(|val| !val.is_null())?,
+        })
+    }
+}
+
+/// Information about a method of a [.NET class](struct@Class).
+#[derive(Copy, Clone)]
+pub struct MethodInfo {
+    /// The address of the compiled code of the method.
+    pub address: Address,
+    /// The amount of parameters of the method, not including `this`.
+    pub param_count: u32,
+    /// The class of the value returned by the method. This is [`None`] for
+    /// methods returning `void` or primitive and generic types. This is
+    /// currently only resolved for the [`Base`](Version::Base) and
+    /// [`V2019`](Version::V2019) versions, as later versions refer to the
+    /// type through the global metadata.
+    pub return_type: Option<Class>,
+}
+
+#[derive(Copy, Clone)]
+struct Method {
+    method: Address,
+}
+
+impl Method {
+    fn name_matches(&self, process: &Process, module: &Module, expected: &str) -> bool {
+        process
+            .read_pointer(
+                self.method + module.offsets.methodinfo_name,
+                module.pointer_size,
+            )
+            .is_ok_and(|name| string::c_str_matches(process, name, expected))
+    }
+
+    fn get_info(&self, process: &Process, module: &Module) -> Option<MethodInfo> {
+        // The pointer to the code is the first field of `MethodInfo`.
+        let address = process
+            .read_pointer(self.method, module.pointer_size)
+            .ok()?;
+
+        let param_count = process
+            .read::<u8>(self.method + module.offsets.methodinfo_parameters_count)
+            .ok()?;
+
+        let return_type = match module.version {
+            Version::Base | Version::V2019 => process
+                .read_pointer(
+                    self.method + module.offsets.methodinfo_return_type,
+                    module.pointer_size,
+                )
+                .ok()
+                .filter(|val| !val.is_null())
+                .filter(|&ret| {
+                    process
+                        .read::<u8>(ret + module.offsets.il2cpptype_type)
+                        .is_ok_and(|ty| {
+                            matches!(
+                                TypeKind::from_raw(ty),
+                                Some(TypeKind::Class | TypeKind::ValueType)
+                            )
+                        })
+                })
+                .and_then(|ret| process.read::<i32>(ret).ok())
+                .and_then(|index| u64::try_from(index).ok())
+                .and_then(|index| {
+                    process
+                        .read_pointer(
+                            module.type_info_definition_table
+                                + index.wrapping_mul(module.size_of_ptr()),
+                            module.pointer_size,
+                        )
+                        .ok()
+                })
+                .filter(|val| !val.is_null())
+                .map(|class| Class { class }),
+            _ => None,
+        };
+
+        Some(MethodInfo {
+            address,
+            param_count: param_count as u32,
+            return_type,
+        })
+    }
+}
+
+/// A builder for resolving a [.NET class](struct@Class) and a set of its
+/// fields in one go. This covers the common case of attaching to the
+/// IL2CPP backend, accessing an [image](Image), looking up a class in it and
+/// finding the offsets of some of its fields.
+///
+/// `CAP` is the maximum amount of fields that can be resolved.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn example(process: asr::Process) {
+/// use asr::game_engine::unity::il2cpp::Builder;
+///
+/// let binding = Builder::<2>::new("GameManager")
+///     .field("timer")
+///     .field("level")
+///     .wait_attach(&process)
+///     .await;
+///
+/// let timer_offset = binding.field_offset("timer");
+/// # }
+/// ```
+#[derive(Copy, Clone)]
+pub struct Builder<const CAP: usize> {
+    version: Option<Version>,
+    assembly_name: &'static str,
+    class_name: &'static str,
+    fields: [&'static str; CAP],
+    len: usize,
+}
+
+impl<const CAP: usize> Builder<CAP> {
+    /// Creates a new builder for the class with the name provided. By default
+    /// the class is looked up in the `Assembly-CSharp` [image](Image) and the
+    /// [IL2CPP version](Version) is detected automatically.
+    pub const fn new(class_name: &'static str) -> Self {
+        Self {
+            version: None,
+            assembly_name: "Assembly-CSharp",
+            class_name,
+            fields: [""; CAP],
+            len: 0,
+        }
+    }
+
+    /// Uses the [IL2CPP version](Version) provided instead of detecting it
+    /// automatically.
+    pub const fn version(mut self, version: Version) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Looks up the class in the [image](Image) with the assembly name
+    /// provided instead of `Assembly-CSharp`.
+    pub const fn image(mut self, assembly_name: &'static str) -> Self {
+        self.assembly_name = assembly_name;
+        self
+    }
+
+    /// Adds a field whose offset should be resolved.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if more than `CAP` fields are added.
+    pub const fn field(mut self, field_name: &'static str) -> Self {
+        assert!(
+            self.len < CAP,
+            "More fields were added than `CAP` allows for."
+        );
+        self.fields[self.len] = field_name;
+        self.len += 1;
+        self
+    }
+
+    /// Tries attaching to the game and resolving the class and all of its
+    /// fields. Returns [`None`] if any of the steps fail.
+    pub fn attach(&self, process: &Process) -> Option<Binding<CAP>> {
+        let module = self.attach_module(process)?;
+        let image = module.get_image(process, self.assembly_name)?;
+        let (class, offsets) = self.resolve_class(process, &module, image)?;
+        Some(self.bind(module, image, class, offsets))
+    }
+
+    /// Attaches to the game and resolves the class and all of its fields.
+    ///
+    /// This is the `await`able version of the [`attach`](Self::attach)
+    /// function, yielding back to the runtime between each try. Each step is
+    /// only retried until it succeeds, so waiting for the class to be set up
+    /// doesn't attach to the module and look up the image again every time.
+    pub async fn wait_attach(&self, process: &Process) -> Binding<CAP> {
+        let module = retry(|| self.attach_module(process)).await;
+        let image = retry(|| module.get_image(process, self.assembly_name)).await;
+        let (class, offsets) = retry(|| self.resolve_class(process, &module, image)).await;
+        self.bind(module, image, class, offsets)
+    }
+
+    fn attach_module(&self, process: &Process) -> Option<Module> {
+        match self.version {
+            Some(version) => Module::attach(process, version),
+            _ => Module::attach_auto_detect(process),
+        }
+    }
+
+    /// Looks up the class in the image and resolves the offsets of all the
+    /// fields that got added. Only the fields that got added are looked for,
+    /// so the walk over the fields of the class stops once all of them are
+    /// found.
+    fn resolve_class(
+        &self,
+        process: &Process,
+        module: &Module,
+        image: Image,
+    ) -> Option<(Class, [u32; CAP])> {
+        let class = image.get_class(process, module, self.class_name)?;
+
+        let mut found = [None; CAP];
+        class.resolve_field_offsets(
+            process,
+            module,
+            &self.fields[..self.len],
+            &mut found[..self.len],
+        );
+        let mut offsets = [0; CAP];
+        for (offset, found) in offsets.iter_mut().zip(&found[..self.len]) {
+            *offset = (*found)?;
+        }
+
+        Some((class, offsets))
+    }
+
+    const fn bind(
+        &self,
+        module: Module,
+        image: Image,
+        class: Class,
+        offsets: [u32; CAP],
+    ) -> Binding<CAP> {
+        Binding {
+            module,
+            image,
+            class,
+            fields: self.fields,
+            offsets,
+            len: self.len,
+        }
+    }
+}
+
+/// The result of resolving a [`Builder`]. This holds the [`Module`], the
+/// [`Image`] and the [.NET class](struct@Class) that got resolved, as well as
+/// the offsets of all the fields.
+pub struct Binding<const CAP: usize> {
+    /// The module the class got resolved with.
+    pub module: Module,
+    /// The image the class is part of.
+    pub image: Image,
+    /// The class that got resolved.
+    pub class: Class,
+    fields: [&'static str; CAP],
+    offsets: [u32; CAP],
+    len: usize,
+}
+
+impl<const CAP: usize> Binding<CAP> {
+    /// Returns the offset of the field with the specified name. The field
+    /// needs to have been added to the [`Builder`].
+    pub fn field_offset(&self, field_name: &str) -> Option<u32> {
+        let index = self.fields[..self.len]
+            .iter()
+            .position(|&name| name == field_name)?;
+        Some(self.offsets[index])
+    }
+
+    /// Returns the offsets of all the fields in the order they were added to
+    /// the [`Builder`].
+    pub fn field_offsets(&self) -> &[u32] {
+        &self.offsets[..self.len]
+    }
 }
 
 /// An IL2CPP-specific implementation for automatic pointer path resolution
@@ -644,23 +2434,13 @@ impl<const CAP: usize> UnityPointer<CAP> {
                 _ => {
                     let current_class = match i {
                         0 => starting_class,
-                        _ => {
-                            let class = process
-                                .read_pointer(class_instance, module.pointer_size)
-                                .ok()
-                                .filter(|val| !val.is_null())
-                                .ok_or(Error {})?;
-                            Class { class }
-                        }
+                        _ => module
+                            .get_class_of_instance(process, class_instance)
+                            .ok_or(Error {})?,
                     };
 
                     let val = current_class
-                        .fields(process, module)
-                        .find(|field| {
-                            field
-                                .get_name::<CSTR>(process, module)
-                                .is_ok_and(|name| name.matches(self.fields[i]))
-                        })
+                        .get_field(process, module, self.fields[i])
                         .ok_or(Error {})?
                         .get_offset(process, module)
                         .ok_or(Error {})? as u64;
@@ -740,13 +2520,30 @@ struct Offsets {
     monoimage_metadatahandle: u8,
     monoclass_name: u8,
     monoclass_name_space: u8,
+    monoclass_byval_arg: u8,
     monoclass_fields: u8,
     monoclass_field_count: u16,
     monoclass_static_fields: u8,
+    monoclass_declaring_type: u8,
     monoclass_parent: u8,
+    monoclass_element_class: u8,
+    monoclass_element_size: u16,
+    monoclass_instance_size: u8,
     monoclassfield_structsize: u8,
+    monoclassfield_type: u8,
     monoclassfield_name: u8,
+    monoclassfield_parent: u8,
     monoclassfield_offset: u8,
+    monoclass_methods: u8,
+    monoclass_method_count: u16,
+    monoclass_token: u16,
+    monoclass_nested_types: u8,
+    monoclass_nested_type_count: u16,
+    methodinfo_name: u8,
+    methodinfo_return_type: u8,
+    methodinfo_parameters_count: u8,
+    il2cpptype_attrs: u8,
+    il2cpptype_type: u8,
 }
 
 impl Offsets {
@@ -762,13 +2559,30 @@ impl Offsets {
                         monoimage_metadatahandle: 0x18, // MonoImage.typeStart
                         monoclass_name: 0x10,
                         monoclass_name_space: 0x18,
+                        monoclass_byval_arg: 0x20,
                         monoclass_fields: 0x80,
                         monoclass_field_count: 0x114,
                         monoclass_static_fields: 0xB8,
+                        monoclass_declaring_type: 0x50,
                         monoclass_parent: 0x58,
+                        monoclass_element_class: 0x40,
+                        monoclass_element_size: 0xF4,
+                        monoclass_instance_size: 0xEC,
                         monoclassfield_structsize: 0x20,
+                        monoclassfield_type: 0x8,
                         monoclassfield_name: 0x0,
+                        monoclassfield_parent: 0x10,
                         monoclassfield_offset: 0x18,
+                        monoclass_methods: 0x98,
+                        monoclass_method_count: 0x110,
+                        monoclass_token: 0x10C,
+                        monoclass_nested_types: 0xA0,
+                        monoclass_nested_type_count: 0x118,
+                        methodinfo_name: 0x10,
+                        methodinfo_return_type: 0x20,
+                        methodinfo_parameters_count: 0x4A,
+                        il2cpptype_attrs: 0x8,
+                        il2cpptype_type: 0xA,
                     },
                     Version::V2019 => &Self {
                         monoassembly_image: 0x0,
@@ -778,13 +2592,30 @@ impl Offsets {
                         monoimage_metadatahandle: 0x18, // MonoImage.typeStart
                         monoclass_name: 0x10,
                         monoclass_name_space: 0x18,
+                        monoclass_byval_arg: 0x20,
                         monoclass_fields: 0x80,
                         monoclass_field_count: 0x11C,
                         monoclass_static_fields: 0xB8,
+                        monoclass_declaring_type: 0x50,
                         monoclass_parent: 0x58,
+                        monoclass_element_class: 0x40,
+                        monoclass_element_size: 0xFC,
+                        monoclass_instance_size: 0xF4,
                         monoclassfield_structsize: 0x20,
+                        monoclassfield_type: 0x8,
                         monoclassfield_name: 0x0,
+                        monoclassfield_parent: 0x10,
                         monoclassfield_offset: 0x18,
+                        monoclass_methods: 0x98,
+                        monoclass_method_count: 0x118,
+                        monoclass_token: 0x114,
+                        monoclass_nested_types: 0xA0,
+                        monoclass_nested_type_count: 0x120,
+                        methodinfo_name: 0x10,
+                        methodinfo_return_type: 0x20,
+                        methodinfo_parameters_count: 0x4A,
+                        il2cpptype_attrs: 0x8,
+                        il2cpptype_type: 0xA,
                     },
                     Version::V2020 => &Self {
                         monoassembly_image: 0x0,
@@ -794,21 +2625,278 @@ impl Offsets {
                         monoimage_metadatahandle: 0x28,
                         monoclass_name: 0x10,
                         monoclass_name_space: 0x18,
+                        monoclass_byval_arg: 0x20,
+                        monoclass_fields: 0x80,
+                        monoclass_field_count: 0x120,
+                        monoclass_static_fields: 0xB8,
+                        monoclass_declaring_type: 0x50,
+                        monoclass_parent: 0x58,
+                        monoclass_element_class: 0x40,
+                        monoclass_element_size: 0x100,
+                        monoclass_instance_size: 0xF4,
+                        monoclassfield_structsize: 0x20,
+                        monoclassfield_type: 0x8,
+                        monoclassfield_name: 0x0,
+                        monoclassfield_parent: 0x10,
+                        monoclassfield_offset: 0x18,
+                        monoclass_methods: 0x98,
+                        monoclass_method_count: 0x11C,
+                        monoclass_token: 0x118,
+                        monoclass_nested_types: 0xA0,
+                        monoclass_nested_type_count: 0x124,
+                        methodinfo_name: 0x10,
+                        methodinfo_return_type: 0x20,
+                        methodinfo_parameters_count: 0x4A,
+                        il2cpptype_attrs: 0x8,
+                        il2cpptype_type: 0xA,
+                    },
+                    Version::V2021 => &Self {
+                        monoassembly_image: 0x0,
+                        monoassembly_aname: 0x18,
+                        monoassemblyname_name: 0x0,
+                        monoimage_typecount: 0x18,
+                        monoimage_metadatahandle: 0x28,
+                        monoclass_name: 0x10,
+                        monoclass_name_space: 0x18,
+                        monoclass_byval_arg: 0x20,
                         monoclass_fields: 0x80,
                         monoclass_field_count: 0x120,
                         monoclass_static_fields: 0xB8,
+                        monoclass_declaring_type: 0x50,
                         monoclass_parent: 0x58,
+                        monoclass_element_class: 0x40,
+                        monoclass_element_size: 0x100,
+                        monoclass_instance_size: 0xF4,
                         monoclassfield_structsize: 0x20,
+                        monoclassfield_type: 0x8,
                         monoclassfield_name: 0x0,
+                        monoclassfield_parent: 0x10,
                         monoclassfield_offset: 0x18,
+                        monoclass_methods: 0x98,
+                        monoclass_method_count: 0x11C,
+                        monoclass_token: 0x118,
+                        monoclass_nested_types: 0xA0,
+                        monoclass_nested_type_count: 0x124,
+                        methodinfo_name: 0x18,
+                        methodinfo_return_type: 0x28,
+                        methodinfo_parameters_count: 0x52,
+                        il2cpptype_attrs: 0x8,
+                        il2cpptype_type: 0xA,
                     },
                 })
             }
+            // On 32-bit the pointers in the structs are half the size, and
+            // the tail of `Il2CppClass` is packed differently, as the extra
+            // field of the 2019 layout fits into the alignment padding.
+            PointerSize::Bit32 => Some(match version {
+                Version::Base => &Self {
+                    monoassembly_image: 0x0,
+                    monoassembly_aname: 0x10,
+                    monoassemblyname_name: 0x0,
+                    monoimage_typecount: 0x10,
+                    monoimage_metadatahandle: 0xC, // MonoImage.typeStart
+                    monoclass_name: 0x8,
+                    monoclass_name_space: 0xC,
+                    monoclass_byval_arg: 0x10,
+                    monoclass_fields: 0x40,
+                    monoclass_field_count: 0xA8,
+                    monoclass_static_fields: 0x5C,
+                    monoclass_declaring_type: 0x28,
+                    monoclass_parent: 0x2C,
+                    monoclass_element_class: 0x20,
+                    monoclass_element_size: 0x88,
+                    monoclass_instance_size: 0x80,
+                    monoclassfield_structsize: 0x14,
+                    monoclassfield_type: 0x4,
+                    monoclassfield_name: 0x0,
+                    monoclassfield_parent: 0x8,
+                    monoclassfield_offset: 0xC,
+                    monoclass_methods: 0x4C,
+                    monoclass_method_count: 0xA4,
+                    monoclass_token: 0xA0,
+                    monoclass_nested_types: 0x50,
+                    monoclass_nested_type_count: 0xAC,
+                    methodinfo_name: 0x8,
+                    methodinfo_return_type: 0x10,
+                    methodinfo_parameters_count: 0x2A,
+                    il2cpptype_attrs: 0x4,
+                    il2cpptype_type: 0x6,
+                },
+                Version::V2019 => &Self {
+                    monoassembly_image: 0x0,
+                    monoassembly_aname: 0x10,
+                    monoassemblyname_name: 0x0,
+                    monoimage_typecount: 0x10,
+                    monoimage_metadatahandle: 0xC, // MonoImage.typeStart
+                    monoclass_name: 0x8,
+                    monoclass_name_space: 0xC,
+                    monoclass_byval_arg: 0x10,
+                    monoclass_fields: 0x40,
+                    monoclass_field_count: 0xA8,
+                    monoclass_static_fields: 0x5C,
+                    monoclass_declaring_type: 0x28,
+                    monoclass_parent: 0x2C,
+                    monoclass_element_class: 0x20,
+                    monoclass_element_size: 0x88,
+                    monoclass_instance_size: 0x80,
+                    monoclassfield_structsize: 0x14,
+                    monoclassfield_type: 0x4,
+                    monoclassfield_name: 0x0,
+                    monoclassfield_parent: 0x8,
+                    monoclassfield_offset: 0xC,
+                    monoclass_methods: 0x4C,
+                    monoclass_method_count: 0xA4,
+                    monoclass_token: 0xA0,
+                    monoclass_nested_types: 0x50,
+                    monoclass_nested_type_count: 0xAC,
+                    methodinfo_name: 0x8,
+                    methodinfo_return_type: 0x10,
+                    methodinfo_parameters_count: 0x2A,
+                    il2cpptype_attrs: 0x4,
+                    il2cpptype_type: 0x6,
+                },
+                Version::V2020 => &Self {
+                    monoassembly_image: 0x0,
+                    monoassembly_aname: 0x10,
+                    monoassemblyname_name: 0x0,
+                    monoimage_typecount: 0xC,
+                    monoimage_metadatahandle: 0x18,
+                    monoclass_name: 0x8,
+                    monoclass_name_space: 0xC,
+                    monoclass_byval_arg: 0x10,
+                    monoclass_fields: 0x40,
+                    monoclass_field_count: 0xAC,
+                    monoclass_static_fields: 0x5C,
+                    monoclass_declaring_type: 0x28,
+                    monoclass_parent: 0x2C,
+                    monoclass_element_class: 0x20,
+                    monoclass_element_size: 0x8C,
+                    monoclass_instance_size: 0x80,
+                    monoclassfield_structsize: 0x14,
+                    monoclassfield_type: 0x4,
+                    monoclassfield_name: 0x0,
+                    monoclassfield_parent: 0x8,
+                    monoclassfield_offset: 0xC,
+                    monoclass_methods: 0x4C,
+                    monoclass_method_count: 0xA8,
+                    monoclass_token: 0xA4,
+                    monoclass_nested_types: 0x50,
+                    monoclass_nested_type_count: 0xB0,
+                    methodinfo_name: 0x8,
+                    methodinfo_return_type: 0x10,
+                    methodinfo_parameters_count: 0x2A,
+                    il2cpptype_attrs: 0x4,
+                    il2cpptype_type: 0x6,
+                },
+                Version::V2021 => &Self {
+                    monoassembly_image: 0x0,
+                    monoassembly_aname: 0x10,
+                    monoassemblyname_name: 0x0,
+                    monoimage_typecount: 0xC,
+                    monoimage_metadatahandle: 0x18,
+                    monoclass_name: 0x8,
+                    monoclass_name_space: 0xC,
+                    monoclass_byval_arg: 0x10,
+                    monoclass_fields: 0x40,
+                    monoclass_field_count: 0xAC,
+                    monoclass_static_fields: 0x5C,
+                    monoclass_declaring_type: 0x28,
+                    monoclass_parent: 0x2C,
+                    monoclass_element_class: 0x20,
+                    monoclass_element_size: 0x8C,
+                    monoclass_instance_size: 0x80,
+                    monoclassfield_structsize: 0x14,
+                    monoclassfield_type: 0x4,
+                    monoclassfield_name: 0x0,
+                    monoclassfield_parent: 0x8,
+                    monoclassfield_offset: 0xC,
+                    monoclass_methods: 0x4C,
+                    monoclass_method_count: 0xA8,
+                    monoclass_token: 0xA4,
+                    monoclass_nested_types: 0x50,
+                    monoclass_nested_type_count: 0xB0,
+                    methodinfo_name: 0xC,
+                    methodinfo_return_type: 0x14,
+                    methodinfo_parameters_count: 0x2E,
+                    il2cpptype_attrs: 0x4,
+                    il2cpptype_type: 0x6,
+                },
+            }),
             _ => None,
         }
     }
 }
 
+// The versions only differ in the layout of the tail of `Il2CppClass`, so the
+// offsets of the critical fields before it need to stay in sync. This is
+// checked at compile time to catch any accidental drift.
+const _: () = {
+    let (Some(base), Some(v2019), Some(v2020), Some(v2021)) = (
+        Offsets::new(Version::Base, PointerSize::Bit64),
+        Offsets::new(Version::V2019, PointerSize::Bit64),
+        Offsets::new(Version::V2020, PointerSize::Bit64),
+        Offsets::new(Version::V2021, PointerSize::Bit64),
+    ) else {
+        panic!("64-bit offsets are missing for an IL2CPP version");
+    };
+
+    let mut i = 0;
+    let versions = [base, v2019, v2020, v2021];
+    while i < versions.len() {
+        let offsets = versions[i];
+        assert!(offsets.monoclass_name == 0x10);
+        assert!(offsets.monoclass_name_space == 0x18);
+        assert!(offsets.monoclass_byval_arg == 0x20);
+        assert!(offsets.monoclass_element_class == offsets.monoclass_byval_arg + 0x20);
+        assert!(offsets.monoclass_fields == 0x80);
+        assert!(offsets.monoclass_static_fields == 0xB8);
+        assert!(offsets.monoclass_declaring_type == 0x50);
+        assert!(offsets.monoclass_parent == 0x58);
+        assert!(offsets.monoclass_nested_types == offsets.monoclass_methods + 0x8);
+        assert!(offsets.monoclass_field_count == offsets.monoclass_token + 8);
+        assert!(offsets.monoclass_nested_type_count == offsets.monoclass_field_count + 4);
+        i += 1;
+    }
+
+    assert!(v2019.monoclass_field_count == base.monoclass_field_count + 8);
+    assert!(v2020.monoclass_field_count == v2019.monoclass_field_count + 4);
+    assert!(v2021.monoclass_field_count == v2020.monoclass_field_count);
+
+    let (Some(base), Some(v2019), Some(v2020), Some(v2021)) = (
+        Offsets::new(Version::Base, PointerSize::Bit32),
+        Offsets::new(Version::V2019, PointerSize::Bit32),
+        Offsets::new(Version::V2020, PointerSize::Bit32),
+        Offsets::new(Version::V2021, PointerSize::Bit32),
+    ) else {
+        panic!("32-bit offsets are missing for an IL2CPP version");
+    };
+
+    let mut i = 0;
+    let versions = [base, v2019, v2020, v2021];
+    while i < versions.len() {
+        let offsets = versions[i];
+        assert!(offsets.monoclass_name == 0x8);
+        assert!(offsets.monoclass_name_space == 0xC);
+        assert!(offsets.monoclass_byval_arg == 0x10);
+        assert!(offsets.monoclass_element_class == offsets.monoclass_byval_arg + 0x10);
+        assert!(offsets.monoclass_fields == 0x40);
+        assert!(offsets.monoclass_static_fields == 0x5C);
+        assert!(offsets.monoclass_declaring_type == 0x28);
+        assert!(offsets.monoclass_parent == 0x2C);
+        assert!(offsets.monoclass_nested_types == offsets.monoclass_methods + 0x4);
+        assert!(offsets.monoclass_field_count == offsets.monoclass_token + 8);
+        assert!(offsets.monoclass_nested_type_count == offsets.monoclass_field_count + 4);
+        i += 1;
+    }
+
+    // The extra field of the 2019 layout fits into the alignment padding on
+    // 32-bit, so unlike on 64-bit, the tail only moves with the 2020 layout.
+    assert!(base.monoclass_field_count == 0xA8);
+    assert!(v2019.monoclass_field_count == base.monoclass_field_count);
+    assert!(v2020.monoclass_field_count == v2019.monoclass_field_count + 4);
+    assert!(v2021.monoclass_field_count == v2020.monoclass_field_count);
+};
+
 /// The version of IL2CPP that was used for the game.
 #[non_exhaustive]
 #[derive(Copy, Clone, PartialEq, Hash, Debug)]
@@ -819,17 +2907,27 @@ pub enum Version {
     V2019,
     /// The version used in 2020.
     V2020,
+    /// The version used in 2021.2 and later, which uses metadata version 29.
+    /// The layout of the classes matches [`V2020`](Self::V2020), but methods
+    /// store an additional pointer to the code for virtual calls.
+    V2021,
 }
 
-fn detect_version(process: &Process) -> Option<Version> {
+/// Detects the [IL2CPP version](Version) that the game is using, without
+/// attaching to it. This is the same detection that
+/// [`Module::attach_auto_detect`] uses, so it can be used to check the version
+/// before attaching, such as for logging it.
+pub fn detect_version(process: &Process) -> Option<Version> {
     let unity_module = {
         let address = process.get_module_address("UnityPlayer.dll").ok()?;
         let size = pe::read_size_of_image(process, address)? as u64;
         (address, size)
     };
 
+    // The signature for the metadata version only works for 64-bit games,
+    // so the version string is used for 32-bit games instead.
     if pe::MachineType::read(process, unity_module.0)? == pe::MachineType::X86 {
-        return Some(Version::Base);
+        return Some(version_from_unity_version(process, unity_module));
     }
 
     const SIG_202X: Signature<6> = Signature::new("00 32 30 32 ?? 2E");
@@ -842,15 +2940,17 @@ fn detect_version(process: &Process) -> Option<Version> {
             let size = pe::read_size_of_image(process, address)? as u64;
 
             let ptr = {
-                let addr = SIG.scan_process_range(process, (address, size))? + 6;
-                addr + 0x4 + process.read::<i32>(addr).ok()?
+                let addr = SIG.scan_process_range(process, (address, size))? + 3;
+                process.read_rip_relative(addr, 3, 7).ok()?
             };
 
             let addr = process.read::<Address64>(ptr).ok()?;
             process.read::<u32>(addr + 0x4).ok()?
         };
 
-        Some(if il2cpp_version >= 27 {
+        Some(if il2cpp_version >= 29 {
+            Version::V2021
+        } else if il2cpp_version >= 27 {
             Version::V2020
         } else {
             Version::V2019
@@ -861,3 +2961,17 @@ fn detect_version(process: &Process) -> Option<Version> {
         Some(Version::Base)
     }
 }
+
+/// Derives the IL2CPP version from the version of Unity that the game is
+/// using. This is less precise than reading the metadata version, as the
+/// metadata version doesn't change in lockstep with the Unity version.
+fn version_from_unity_version(process: &Process, unity_module: (Address, u64)) -> Version {
+    match super::read_unity_version(process, unity_module) {
+        // IL2CPP metadata version 29 got introduced with Unity 2021.2
+        Some((major, minor)) if major > 2021 || (major == 2021 && minor >= 2) => Version::V2021,
+        // IL2CPP metadata version 27 got introduced with Unity 2020.2
+        Some((major, minor)) if major > 2020 || (major == 2020 && minor >= 2) => Version::V2020,
+        Some((major, _)) if major >= 2019 => Version::V2019,
+        _ => Version::Base,
+    }
+}
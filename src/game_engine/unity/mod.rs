@@ -86,8 +86,337 @@
 pub mod il2cpp;
 pub mod mono;
 
+mod primitives;
 mod scene;
-pub use self::scene::*;
+pub use self::{primitives::*, scene::*};
+
+use core::str;
+
+use arrayvec::ArrayString;
+use bytemuck::CheckedBitPattern;
+
+use crate::{file_format::pe, signature::Signature, Address, Error, Process};
+
+/// The scripting backend that a Unity game is using.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScriptingBackend {
+    /// The game is using the Mono runtime. Use the [`mono`] module to access
+    /// it.
+    Mono,
+    /// The game is compiled ahead of time with IL2CPP. Use the [`il2cpp`]
+    /// module to access it.
+    Il2Cpp,
+}
+
+/// Detects which [scripting backend](ScriptingBackend) the game is using,
+/// based on the modules that are loaded into the process. This doesn't
+/// attach to the backend, so it can be used to pick the right backend before
+/// attaching. Returns [`None`] if neither backend is loaded (yet). Only the
+/// Windows builds of the backends are detected, as attaching to the native
+/// Linux builds is not supported.
+pub fn detect_scripting_backend(process: &Process) -> Option<ScriptingBackend> {
+    let is_loaded = |names: &[&str]| {
+        names
+            .iter()
+            .any(|&name| process.get_module_address(name).is_ok())
+    };
+
+    if is_loaded(&["GameAssembly.dll"]) {
+        Some(ScriptingBackend::Il2Cpp)
+    } else if is_loaded(&["mono.dll", "mono-2.0-bdwgc.dll"]) {
+        Some(ScriptingBackend::Mono)
+    } else {
+        None
+    }
+}
+
+/// An error returned when attaching to the scripting backend of a Unity game
+/// fails. This describes the step at which attaching failed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AttachError {
+    /// The version of the scripting backend could not be detected. Either
+    /// the backend is not loaded (yet) or the game is using a version that
+    /// is not supported.
+    VersionDetectionFailed,
+    /// The version got detected, but the structures of the scripting backend
+    /// are not set up yet. The game may still be initializing the scripting
+    /// backend.
+    AttachFailed,
+    /// The module of the scripting backend is not loaded (yet).
+    ModuleNotFound,
+    /// A signature or export that is needed to find the structures of the
+    /// scripting backend could not be found. The game may be using a build of
+    /// the scripting backend that is not supported.
+    SignatureNotFound,
+    /// The architecture of the game is not supported for the version of the
+    /// scripting backend.
+    UnsupportedArchitecture,
+    /// Reading the memory of the process failed.
+    MemoryReadFailed,
+    /// Attaching succeeded, but the `Assembly-CSharp` image could not be
+    /// found. The game may still be loading its assemblies.
+    ImageResolutionFailed,
+}
+
+/// A kind of collection from the .NET class library, whose amount of elements
+/// can be read via `collection_count` on the [Mono](mono::Module::collection_count)
+/// or [IL2CPP](il2cpp::Module::collection_count) module.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CollectionKind {
+    /// A `System.Collections.Generic.List<T>`.
+    List,
+    /// A `System.Collections.Generic.Dictionary<TKey, TValue>`.
+    Dictionary,
+    /// A `System.Collections.Generic.HashSet<T>`.
+    HashSet,
+    /// A `System.Collections.Generic.Stack<T>`.
+    Stack,
+    /// A `System.Collections.Generic.Queue<T>`.
+    Queue,
+}
+
+impl CollectionKind {
+    /// The names of the field storing the amount of elements. The names
+    /// differ between the versions of the class library that Unity ships.
+    const fn count_field_names(self) -> &'static [&'static str] {
+        match self {
+            Self::List | Self::Stack | Self::Queue => &["_size"],
+            Self::Dictionary => &["count", "_count"],
+            Self::HashSet => &["m_count", "_count"],
+        }
+    }
+}
+
+/// An entry of a `System.Collections.Generic.Dictionary<TKey, TValue>`, as
+/// yielded by `dictionary_entries` on the
+/// [Mono](mono::Module::dictionary_entries) or
+/// [IL2CPP](il2cpp::Module::dictionary_entries) module. For reference types
+/// the key and the value are pointers, so [`Address64`](crate::Address64) or
+/// [`Address32`](crate::Address32) need to be used as their type.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DictionaryEntry {
+    key: Address,
+    value: Address,
+}
+
+impl DictionaryEntry {
+    /// Returns the address of the key of the entry.
+    pub const fn key_address(&self) -> Address {
+        self.key
+    }
+
+    /// Returns the address of the value of the entry.
+    pub const fn value_address(&self) -> Address {
+        self.value
+    }
+
+    /// Reads the key of the entry.
+    pub fn read_key<K: CheckedBitPattern>(&self, process: &Process) -> Result<K, Error> {
+        process.read(self.key)
+    }
+
+    /// Reads the value of the entry.
+    pub fn read_value<V: CheckedBitPattern>(&self, process: &Process) -> Result<V, Error> {
+        process.read(self.value)
+    }
+}
+
+/// The kind of a .NET type, such as the type of a field. This is the same
+/// for both the Mono and the IL2CPP backend, as both use the element types
+/// defined by the ECMA-335 standard.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TypeKind {
+    /// `void`.
+    Void,
+    /// `bool`.
+    Boolean,
+    /// `char`, a UTF-16 code unit.
+    Char,
+    /// `sbyte`.
+    I1,
+    /// `byte`.
+    U1,
+    /// `short`.
+    I2,
+    /// `ushort`.
+    U2,
+    /// `int`.
+    I4,
+    /// `uint`.
+    U4,
+    /// `long`.
+    I8,
+    /// `ulong`.
+    U8,
+    /// `float`.
+    R4,
+    /// `double`.
+    R8,
+    /// `string`. This is a reference to the string object.
+    String,
+    /// An unmanaged pointer.
+    Ptr,
+    /// A managed reference, such as a `ref` parameter.
+    ByRef,
+    /// A value type, such as a `struct` or an `enum`. The value is stored
+    /// inline.
+    ValueType,
+    /// A reference type. This is a reference to the object.
+    Class,
+    /// A generic parameter of a type.
+    Var,
+    /// A multi-dimensional array.
+    Array,
+    /// An instantiation of a generic type, such as `List<int>`. This can be
+    /// either a value type or a reference type.
+    GenericInst,
+    /// A typed reference.
+    TypedByRef,
+    /// `nint`, a pointer-sized signed integer.
+    I,
+    /// `nuint`, a pointer-sized unsigned integer.
+    U,
+    /// A function pointer.
+    FnPtr,
+    /// `object`. This is a reference to the object.
+    Object,
+    /// A single-dimensional array with a lower bound of zero, such as
+    /// `int[]`.
+    SzArray,
+    /// A generic parameter of a method.
+    MVar,
+}
+
+impl TypeKind {
+    /// Decodes the raw element type that is stored in the type structures
+    /// of both backends.
+    const fn from_raw(raw: u8) -> Option<Self> {
+        Some(match raw {
+            0x01 => Self::Void,
+            0x02 => Self::Boolean,
+            0x03 => Self::Char,
+            0x04 => Self::I1,
+            0x05 => Self::U1,
+            0x06 => Self::I2,
+            0x07 => Self::U2,
+            0x08 => Self::I4,
+            0x09 => Self::U4,
+            0x0A => Self::I8,
+            0x0B => Self::U8,
+            0x0C => Self::R4,
+            0x0D => Self::R8,
+            0x0E => Self::String,
+            0x0F => Self::Ptr,
+            0x10 => Self::ByRef,
+            0x11 => Self::ValueType,
+            0x12 => Self::Class,
+            0x13 => Self::Var,
+            0x14 => Self::Array,
+            0x15 => Self::GenericInst,
+            0x16 => Self::TypedByRef,
+            0x18 => Self::I,
+            0x19 => Self::U,
+            0x1B => Self::FnPtr,
+            0x1C => Self::Object,
+            0x1D => Self::SzArray,
+            0x1E => Self::MVar,
+            _ => return None,
+        })
+    }
+
+    /// Checks whether values of this kind are stored as a reference to an
+    /// object, which needs to be dereferenced to access the object.
+    /// Instantiations of generic types are not considered references, as
+    /// their kind doesn't tell whether they are a value type.
+    pub const fn is_reference(self) -> bool {
+        matches!(
+            self,
+            Self::String | Self::Class | Self::Array | Self::Object | Self::SzArray
+        )
+    }
+}
+
+/// The names of the field of a `Dictionary<TKey, TValue>` that stores the
+/// array of its entries.
+const DICTIONARY_ENTRIES_FIELD_NAMES: [&str; 2] = ["entries", "_entries"];
+
+/// The names of the field of a `List<T>` that stores the array of its
+/// elements.
+const LIST_ITEMS_FIELD_NAMES: [&str; 1] = ["_items"];
+
+/// Finds the address and size of the Unity player module, `UnityPlayer.dll`.
+/// Older versions of Unity embed the player into the executable itself, so
+/// the main module is used as a fallback.
+fn unity_player_range(process: &Process) -> Option<(Address, u64)> {
+    if let Ok(address) = process.get_module_address("UnityPlayer.dll") {
+        let size = pe::read_size_of_image(process, address)? as u64;
+        return Some((address, size));
+    }
+    let (_, address, size) = process.main_module::<128>()?;
+    Some((address, size))
+}
+
+/// Reads the full version string of the Unity engine that the game is using,
+/// such as `2020.3.16f1`. This can be used for logging the version or for
+/// deciding on offsets that differ between versions of Unity.
+pub fn read_version_string(process: &Process) -> Option<ArrayString<32>> {
+    find_unity_version_string(process, unity_player_range(process)?)
+}
+
+/// Scans the Unity player module for the version string of the engine.
+fn find_unity_version_string(
+    process: &Process,
+    unity_module: (Address, u64),
+) -> Option<ArrayString<32>> {
+    const SIG_20XX: Signature<6> = Signature::new("00 32 30 ?? ?? 2E");
+
+    let (mut addr, end) = (unity_module.0, unity_module.0 + unity_module.1);
+
+    // The signature is fairly generic, so we keep looking until we find
+    // something that actually looks like a version string.
+    while addr < end {
+        let found = SIG_20XX.scan_process_range(process, (addr, end.value() - addr.value()))?;
+        addr = found + 1u8;
+
+        let Ok(buf) = process.read::<[u8; 32]>(found + 1u8) else {
+            continue;
+        };
+
+        let len = buf
+            .iter()
+            .position(|&b| !(b.is_ascii_alphanumeric() || b == b'.'))
+            .unwrap_or(buf.len());
+        let version = &buf[..len];
+
+        if version.len() < 6 || !version[..4].iter().all(u8::is_ascii_digit) {
+            continue;
+        }
+
+        // The bytes are all ASCII, so this can't fail.
+        return ArrayString::from(str::from_utf8(version).ok()?).ok();
+    }
+
+    None
+}
+
+/// Scans the Unity player module for the version string of the engine and
+/// returns its major and minor version, e.g. `(2021, 3)` for `2021.3.16f1`.
+fn read_unity_version(process: &Process, unity_module: (Address, u64)) -> Option<(u32, u32)> {
+    let version = find_unity_version_string(process, unity_module)?;
+    let mut parts = version.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts
+        .next()?
+        .bytes()
+        .take_while(u8::is_ascii_digit)
+        .try_fold(0u32, |acc, val| {
+            acc.checked_mul(10)?.checked_add((val - b'0') as u32)
+        })?;
+    Some((major, minor))
+}
 
 fn value_from_string(value: &str) -> Option<u32> {
     if let Some(rem) = value.strip_prefix("0x") {
@@ -2,16 +2,26 @@
 //! backend.
 
 use crate::{
-    deep_pointer::DeepPointer, file_format::pe, future::retry, signature::Signature,
-    string::ArrayCString, Address, Address32, Address64, Error, PointerSize, Process,
+    deep_pointer::DeepPointer,
+    file_format::pe,
+    future::{retry, retry_timeout},
+    signature::Signature,
+    string::{self, ArrayCString},
+    Address, Address32, Address64, Error, PointerSize, Process,
 };
-use core::{array, cell::RefCell, iter};
+use core::{array, cell::RefCell, iter, mem};
 
 #[cfg(feature = "derive")]
 pub use asr_derive::MonoClass as Class;
 use bytemuck::CheckedBitPattern;
 
-const CSTR: usize = 128;
+use super::{
+    AttachError, CollectionKind, DictionaryEntry, TypeKind, DICTIONARY_ENTRIES_FIELD_NAMES,
+    LIST_ITEMS_FIELD_NAMES,
+};
+
+const BUCKET_CHUNK: usize = 64;
+const MAX_MEMBER_COUNT: u32 = u16::MAX as u32;
 
 /// Represents access to a Unity game that is using the standard Mono backend.
 pub struct Module {
@@ -31,57 +41,92 @@ impl Module {
         Self::attach(process, version)
     }
 
+    /// Tries attaching to a Unity game that is using the standard Mono backend,
+    /// automatically detecting the [Mono version](Version). Unlike
+    /// [`attach_auto_detect`](Self::attach_auto_detect), this reports the
+    /// step that failed. Attaching is also only considered successful once the
+    /// `Assembly-CSharp` [image](Image) can be found.
+    pub fn try_attach_auto_detect(process: &Process) -> Result<Self, AttachError> {
+        let version = detect_version(process).ok_or(AttachError::VersionDetectionFailed)?;
+        let module = Self::try_attach(process, version)?;
+        module
+            .get_default_image(process)
+            .ok_or(AttachError::ImageResolutionFailed)?;
+        Ok(module)
+    }
+
     /// Tries attaching to a Unity game that is using the standard Mono backend
     /// with the [Mono version](Version) provided. The version needs to be
     /// correct for this function to work. If you don't know the version in
     /// advance, use [`attach_auto_detect`](Self::attach_auto_detect) instead.
     pub fn attach(process: &Process, version: Version) -> Option<Self> {
+        Self::try_attach(process, version).ok()
+    }
+
+    /// Tries attaching to a Unity game that is using the standard Mono backend
+    /// with the [Mono version](Version) provided, just like
+    /// [`attach`](Self::attach). Unlike that function, this reports why
+    /// attaching failed, which is useful for diagnosing why a specific game
+    /// can't be attached to.
+    pub fn try_attach(process: &Process, version: Version) -> Result<Self, AttachError> {
         let module = ["mono.dll", "mono-2.0-bdwgc.dll"]
             .iter()
-            .find_map(|&name| process.get_module_address(name).ok())?;
+            .find_map(|&name| process.get_module_address(name).ok())
+            .ok_or(AttachError::ModuleNotFound)?;
 
-        let pointer_size = match pe::MachineType::read(process, module)? {
-            pe::MachineType::X86_64 => PointerSize::Bit64,
-            _ => PointerSize::Bit32,
-        };
+        let pointer_size =
+            match pe::MachineType::read(process, module).ok_or(AttachError::MemoryReadFailed)? {
+                pe::MachineType::X86_64 => PointerSize::Bit64,
+                _ => PointerSize::Bit32,
+            };
 
-        let offsets = Offsets::new(version, pointer_size)?;
+        let offsets =
+            Offsets::new(version, pointer_size).ok_or(AttachError::UnsupportedArchitecture)?;
 
-        let root_domain_function_address = pe::symbols(process, module)
-            .find(|symbol| {
-                symbol
-                    .get_name::<25>(process)
-                    .is_ok_and(|name| name.matches("mono_assembly_foreach"))
-            })?
-            .address;
+        let root_domain_function_address =
+            pe::get_export_address(process, module, "mono_assembly_foreach")
+                .ok_or(AttachError::SignatureNotFound)?;
 
         let assemblies_pointer: Address = match pointer_size {
             PointerSize::Bit64 => {
                 const SIG_MONO_64: Signature<3> = Signature::new("48 8B 0D");
-                let scan_address: Address = SIG_MONO_64
-                    .scan_process_range(process, (root_domain_function_address, 0x100))?
-                    + 3;
-                scan_address + 0x4 + process.read::<i32>(scan_address).ok()?
+                let scan_address = SIG_MONO_64
+                    .scan_from(process, root_domain_function_address, 0x100)
+                    .ok_or(AttachError::SignatureNotFound)?;
+                process
+                    .read_rip_relative(scan_address, 3, 7)
+                    .map_err(|_| AttachError::MemoryReadFailed)?
             }
             PointerSize::Bit32 => {
                 const SIG_32_1: Signature<2> = Signature::new("FF 35");
                 const SIG_32_2: Signature<2> = Signature::new("8B 0D");
 
-                let ptr = [SIG_32_1, SIG_32_2].iter().find_map(|sig| {
-                    sig.scan_process_range(process, (root_domain_function_address, 0x100))
-                })? + 2;
+                let ptr = [SIG_32_1, SIG_32_2]
+                    .iter()
+                    .find_map(|sig| sig.scan_from(process, root_domain_function_address, 0x100))
+                    .ok_or(AttachError::SignatureNotFound)?
+                    + 2;
 
-                process.read::<Address32>(ptr).ok()?.into()
+                process
+                    .read::<Address32>(ptr)
+                    .map_err(|_| AttachError::MemoryReadFailed)?
+                    .into()
             }
-            _ => return None,
+            _ => return Err(AttachError::UnsupportedArchitecture),
         };
 
+        // `mono_assembly_foreach` walks Mono's global list of loaded
+        // assemblies. This list is shared by all the application domains, so
+        // assemblies loaded into domains other than the root domain are found
+        // as well.
         let assemblies = process
             .read_pointer(assemblies_pointer, pointer_size)
-            .ok()
-            .filter(|val| !val.is_null())?;
+            .map_err(|_| AttachError::MemoryReadFailed)?;
+        if assemblies.is_null() {
+            return Err(AttachError::AttachFailed);
+        }
 
-        Some(Self {
+        Ok(Self {
             pointer_size,
             version,
             offsets,
@@ -124,16 +169,69 @@ impl Module {
     /// assembly, and contains all the game logic. The
     /// [`get_default_image`](Self::get_default_image) function is a shorthand
     /// for this function that accesses the `Assembly-CSharp` [image](Image).
+    /// The assemblies of all the application domains are searched, not just
+    /// the ones of the root domain.
     pub fn get_image(&self, process: &Process, assembly_name: &str) -> Option<Image> {
         self.assemblies(process)
-            .find(|assembly| {
-                assembly
-                    .get_name::<CSTR>(process, self)
-                    .is_ok_and(|name| name.matches(assembly_name))
-            })?
+            .find(|assembly| assembly.name_matches(process, self, assembly_name))?
             .get_image(process, self)
     }
 
+    /// Looks for the first of the specified binary [images](Image) that can
+    /// be found inside the target process. The names are tried in order. This
+    /// is useful if the name of an assembly differs between builds of a game.
+    pub fn get_image_any(&self, process: &Process, assembly_names: &[&str]) -> Option<Image> {
+        assembly_names
+            .iter()
+            .find_map(|assembly_name| self.get_image(process, assembly_name))
+    }
+
+    /// Tries attaching to a Unity game that is using the standard Mono backend
+    /// with the [Mono version](Version) provided, just like [`attach`](Self::attach).
+    /// Additionally this verifies that the `Assembly-CSharp`
+    /// [image](Image) can be found, so clearly bogus pointers are rejected
+    /// upfront, instead of causing failures later on. This also returns
+    /// [`None`] while the game is still loading its assemblies.
+    pub fn attach_verified(process: &Process, version: Version) -> Option<Self> {
+        let module = Self::attach(process, version)?;
+        module.get_default_image(process)?;
+        Some(module)
+    }
+
+    /// Tries to find the [.NET class](struct@Class) with the specified
+    /// namespace and name in the [image](Image) of the assembly specified.
+    /// This is the most precise way to look up a class, which is useful if
+    /// multiple assemblies, such as plugins, define classes with the same
+    /// namespace and name.
+    pub fn get_class_in(
+        &self,
+        process: &Process,
+        assembly_name: &str,
+        namespace: &str,
+        class_name: &str,
+    ) -> Option<Class> {
+        self.get_image(process, assembly_name)?
+            .get_class_in_namespace(process, self, namespace, class_name)
+    }
+
+    /// Searches the [images](Image) of all the loaded assemblies for the
+    /// [.NET class](struct@Class) with the specified namespace and name. The
+    /// first class that is found is returned together with the
+    /// [image](Image) that it is part of. If you know the assembly that the
+    /// class is part of, use [`get_class_in`](Self::get_class_in) instead.
+    pub fn find_class(
+        &self,
+        process: &Process,
+        namespace: &str,
+        class_name: &str,
+    ) -> Option<(Image, Class)> {
+        self.assemblies(process).find_map(|assembly| {
+            let image = assembly.get_image(process, self)?;
+            let class = image.get_class_in_namespace(process, self, namespace, class_name)?;
+            Some((image, class))
+        })
+    }
+
     /// Looks for the `Assembly-CSharp` binary [image](Image) inside the target
     /// process. An [image](Image) is a .NET DLL that is loaded
     /// by the game. The `Assembly-CSharp` [image](Image) is the main
@@ -156,6 +254,19 @@ impl Module {
         retry(|| Self::attach_auto_detect(process)).await
     }
 
+    /// Attaches to a Unity game that is using the standard Mono backend, automatically
+    /// detecting the [Mono version](Version) on every try. This is the
+    /// `await`able version of the [`attach_auto_detect`](Self::attach_auto_detect)
+    /// function, yielding back to the runtime between each try. Unlike
+    /// [`wait_attach_auto_detect`](Self::wait_attach_auto_detect), this gives
+    /// up after `max_ticks` tries and returns [`None`] in that case.
+    pub async fn wait_attach_auto_detect_timeout(
+        process: &Process,
+        max_ticks: u64,
+    ) -> Option<Module> {
+        retry_timeout(|| Self::attach_auto_detect(process), max_ticks).await
+    }
+
     /// Attaches to a Unity game that is using the standard Mono backend with the
     /// [Mono version](Version) provided. The version needs to be correct
     /// for this function to work. If you don't know the version in advance, use
@@ -167,6 +278,30 @@ impl Module {
         retry(|| Self::attach(process, version)).await
     }
 
+    /// Attaches to a Unity game with the [Mono version](Version) provided. This is the
+    /// `await`able version of the [`attach`](Self::attach) function, yielding
+    /// back to the runtime between each try. Unlike
+    /// [`wait_attach`](Self::wait_attach), this gives up after `max_ticks`
+    /// tries and returns [`None`] in that case.
+    pub async fn wait_attach_timeout(
+        process: &Process,
+        version: Version,
+        max_ticks: u64,
+    ) -> Option<Self> {
+        retry_timeout(|| Self::attach(process, version), max_ticks).await
+    }
+
+    /// Attaches to a Unity game that is using the standard Mono backend with the
+    /// [Mono version](Version) provided and verifies that the `Assembly-CSharp`
+    /// [image](Image) can be found.
+    ///
+    /// This is the `await`able version of the
+    /// [`attach_verified`](Self::attach_verified) function, yielding back to
+    /// the runtime between each try.
+    pub async fn wait_attach_verified(process: &Process, version: Version) -> Module {
+        retry(|| Self::attach_verified(process, version)).await
+    }
+
     /// Looks for the specified binary [image](Image) inside the target process.
     /// An [image](Image) is a .NET DLL that is loaded
     /// by the game. The `Assembly-CSharp` [image](Image) is the main game
@@ -181,6 +316,28 @@ impl Module {
         retry(|| self.get_image(process, assembly_name)).await
     }
 
+    /// Looks for the specified binary [image](Image) inside the target process.
+    /// This is the `await`able version of the [`get_image`](Self::get_image)
+    /// function, yielding back to the runtime between each try. Unlike
+    /// [`wait_get_image`](Self::wait_get_image), this gives up after
+    /// `max_ticks` tries and returns [`None`] in that case.
+    pub async fn wait_get_image_timeout(
+        &self,
+        process: &Process,
+        assembly_name: &str,
+        max_ticks: u64,
+    ) -> Option<Image> {
+        retry_timeout(|| self.get_image(process, assembly_name), max_ticks).await
+    }
+
+    /// Looks for the first of the specified binary [images](Image) that can
+    /// be found inside the target process. This is the `await`able version of
+    /// the [`get_image_any`](Self::get_image_any) function, yielding back to
+    /// the runtime between each try.
+    pub async fn wait_get_image_any(&self, process: &Process, assembly_names: &[&str]) -> Image {
+        retry(|| self.get_image_any(process, assembly_names)).await
+    }
+
     /// Looks for the `Assembly-CSharp` binary [image](Image) inside the target
     /// process. An [image](Image) is a .NET DLL that
     /// is loaded by the game. The `Assembly-CSharp` [image](Image) is the main
@@ -195,6 +352,318 @@ impl Module {
         retry(|| self.get_default_image(process)).await
     }
 
+    /// Looks for the `Assembly-CSharp` binary [image](Image) inside the target
+    /// process. This is the `await`able version of the
+    /// [`get_default_image`](Self::get_default_image) function, yielding back
+    /// to the runtime between each try. Unlike
+    /// [`wait_get_default_image`](Self::wait_get_default_image), this gives up
+    /// after `max_ticks` tries and returns [`None`] in that case.
+    pub async fn wait_get_default_image_timeout(
+        &self,
+        process: &Process,
+        max_ticks: u64,
+    ) -> Option<Image> {
+        retry_timeout(|| self.get_default_image(process), max_ticks).await
+    }
+
+    /// Returns the [.NET class](struct@Class) of the object stored at the
+    /// address given. This is useful if a field is declared as a base type, but
+    /// the actual object stored in it may be of various derived types.
+    pub fn get_class_of_instance(&self, process: &Process, instance: Address) -> Option<Class> {
+        // The object header starts with a pointer to the MonoVTable, whose
+        // first field in turn points to the class.
+        let vtable = process
+            .read_pointer(instance, self.pointer_size)
+            .ok()
+            .filter(|val| !val.is_null())?;
+
+        Some(Class {
+            class: process
+                .read_pointer(vtable, self.pointer_size)
+                .ok()
+                .filter(|val| !val.is_null())?,
+        })
+    }
+
+    /// Returns the size of the header that every managed object starts with.
+    /// The fields of an instance of a class follow the header. In Mono this is
+    /// the `MonoObject` struct (`mono/metadata/object-internals.h`), which
+    /// consists of the pointer to the vtable and the pointer to the
+    /// synchronisation data, so it is the size of 2 pointers for all versions.
+    pub const fn object_header_size(&self) -> u64 {
+        2 * self.size_of_ptr()
+    }
+
+    /// Reads the value of an instance field with the specified name from the
+    /// instance given. The field is looked up on the class of the instance,
+    /// so no [class](struct@Class) needs to be found upfront. Returns
+    /// [`None`] if the field doesn't exist or reading it fails. Nothing is
+    /// cached, so the field is looked up on every call. If the field is read
+    /// repeatedly, look up its offset once instead.
+    pub fn read_field<T: CheckedBitPattern>(
+        &self,
+        process: &Process,
+        instance: Address,
+        field_name: &str,
+    ) -> Option<T> {
+        let offset = self.find_instance_field_offset(process, instance, &[field_name])?;
+        process.read(instance + offset).ok()
+    }
+
+    /// Returns the amount of elements stored in an instance of one of the
+    /// common [collections](CollectionKind) of the .NET class library. The
+    /// field storing the amount is looked up on the class of the instance, so
+    /// this works regardless of the layout of the class.
+    pub fn collection_count(
+        &self,
+        process: &Process,
+        instance: Address,
+        kind: CollectionKind,
+    ) -> Option<i32> {
+        let offset =
+            self.find_instance_field_offset(process, instance, kind.count_field_names())?;
+        process.read(instance + offset).ok()
+    }
+
+    /// Returns the address of the first entry of an instance of a
+    /// `System.Collections.Generic.Dictionary<TKey, TValue>`. Each entry
+    /// consists of the hash code, the index of the next entry, the key and
+    /// the value. The entries are stored consecutively, with
+    /// [`dictionary_entry_stride`](Self::dictionary_entry_stride) bytes
+    /// between them. Entries that got removed are not cleared, so they are
+    /// still part of the array.
+    pub fn dictionary_entries_address(
+        &self,
+        process: &Process,
+        dictionary_instance: Address,
+    ) -> Option<Address> {
+        let entries = self.read_dictionary_entries(process, dictionary_instance)?;
+        Some(self.array_elements_address(entries))
+    }
+
+    /// Returns the size of a single entry of an instance of a
+    /// `System.Collections.Generic.Dictionary<TKey, TValue>`. This depends on
+    /// the types of the keys and values.
+    pub fn dictionary_entry_stride(
+        &self,
+        process: &Process,
+        dictionary_instance: Address,
+    ) -> Option<u64> {
+        let entries = self.read_dictionary_entries(process, dictionary_instance)?;
+        let entry_class = self
+            .get_class_of_instance(process, entries)?
+            .get_element_class(process, self)?;
+        self.value_type_size(process, entry_class)
+    }
+
+    /// Returns the size of the value type given, when it is stored inline,
+    /// such as in an array. The instance size of a value type includes the
+    /// object header, even though it isn't stored in that case.
+    fn value_type_size(&self, process: &Process, class: Class) -> Option<u64> {
+        (class.get_instance_size(process, self)? as u64)
+            .checked_sub(self.object_header_size())
+            .filter(|&size| size != 0)
+    }
+
+    /// Iterates over the entries of an instance of a
+    /// `System.Collections.Generic.Dictionary<TKey, TValue>`. Entries that got
+    /// removed are still part of the array of entries, but they are skipped.
+    /// The offsets of the key and the value within an entry are looked up on
+    /// the class of the entries, so this works for any types of keys and
+    /// values.
+    pub fn dictionary_entries<'a>(
+        &'a self,
+        process: &'a Process,
+        dictionary_instance: Address,
+    ) -> Option<impl Iterator<Item = DictionaryEntry> + 'a> {
+        let entries = self.read_dictionary_entries(process, dictionary_instance)?;
+        let entry_class = self
+            .get_class_of_instance(process, entries)?
+            .get_element_class(process, self)?;
+
+        // The entries are value types, so the offsets of their fields and
+        // their instance size include the object header, even though it isn't
+        // stored in the array.
+        let header_size = self.object_header_size();
+        let field_offset = |name| {
+            (entry_class.get_field_offset(process, self, name)? as u64).checked_sub(header_size)
+        };
+        let key_offset = field_offset("key")?;
+        let value_offset = field_offset("value")?;
+        let stride = self.value_type_size(process, entry_class)?;
+
+        // The count includes the entries that got removed, but never exceeds
+        // the length of the array.
+        let count =
+            self.collection_count(process, dictionary_instance, CollectionKind::Dictionary)?;
+        let count = (count.max(0) as u32).min(self.array_len(process, entries)?);
+        let first_entry = self.array_elements_address(entries);
+
+        Some(
+            (0..count as u64)
+                .map(move |index| first_entry + index * stride)
+                // Removed entries have their hash code set to -1.
+                .filter(move |&entry| process.read::<i32>(entry).is_ok_and(|hash| hash >= 0))
+                .map(move |entry| DictionaryEntry {
+                    key: entry + key_offset,
+                    value: entry + value_offset,
+                }),
+        )
+    }
+
+    fn read_dictionary_entries(
+        &self,
+        process: &Process,
+        dictionary_instance: Address,
+    ) -> Option<Address> {
+        let offset = self.find_instance_field_offset(
+            process,
+            dictionary_instance,
+            &DICTIONARY_ENTRIES_FIELD_NAMES,
+        )?;
+        process
+            .read_pointer(dictionary_instance + offset, self.pointer_size)
+            .ok()
+            .filter(|val| !val.is_null())
+    }
+
+    fn find_instance_field_offset(
+        &self,
+        process: &Process,
+        instance: Address,
+        field_names: &[&str],
+    ) -> Option<u32> {
+        let class = self.get_class_of_instance(process, instance)?;
+        field_names
+            .iter()
+            .find_map(|name| class.get_field_offset(process, self, name))
+    }
+
+    /// Returns the length of a .NET array, such as the value of a field of
+    /// type `int[]`.
+    pub fn array_len(&self, process: &Process, array: Address) -> Option<u32> {
+        // The length follows the object header and the bounds.
+        process
+            .read::<u32>(array + self.object_header_size() + self.size_of_ptr())
+            .ok()
+    }
+
+    /// Returns the address of the first element of a .NET array. The elements
+    /// are stored consecutively, with reference types being stored as
+    /// pointers.
+    pub fn array_elements_address(&self, array: Address) -> Address {
+        // The elements follow the object header, the bounds and the length.
+        array + self.object_header_size() + 2 * self.size_of_ptr()
+    }
+
+    /// Reads the element at the index given from a .NET array. Returns
+    /// [`None`] if the index is out of bounds or reading the element fails.
+    /// The elements are spaced apart by the element size of the array's
+    /// class, so [`None`] is also returned if the size of `T` doesn't match
+    /// it. For reference types the elements are pointers, so
+    /// [`Address64`](crate::Address64) or [`Address32`](crate::Address32)
+    /// need to be used as the type of the elements.
+    pub fn read_array_element<T: CheckedBitPattern>(
+        &self,
+        process: &Process,
+        array: Address,
+        index: u32,
+    ) -> Option<T> {
+        if index >= self.array_len(process, array)? {
+            return None;
+        }
+        let element_size = self
+            .get_class_of_instance(process, array)?
+            .get_element_size(process, self)?;
+        if element_size as usize != mem::size_of::<T>() {
+            return None;
+        }
+        let offset = (index as u64).checked_mul(element_size as u64)?;
+        process
+            .read(self.array_elements_address(array) + offset)
+            .ok()
+    }
+
+    /// Returns the amount of elements stored in an instance of a
+    /// `System.Collections.Generic.List<T>`.
+    pub fn list_len(&self, process: &Process, list_instance: Address) -> Option<u32> {
+        self.collection_count(process, list_instance, CollectionKind::List)
+            .and_then(|len| u32::try_from(len).ok())
+    }
+
+    /// Returns the address of the first element stored in an instance of a
+    /// `System.Collections.Generic.List<T>`. The elements are stored
+    /// consecutively, with reference types being stored as pointers.
+    pub fn list_items_address(&self, process: &Process, list_instance: Address) -> Option<Address> {
+        let offset =
+            self.find_instance_field_offset(process, list_instance, &LIST_ITEMS_FIELD_NAMES)?;
+        let items = process
+            .read_pointer(list_instance + offset, self.pointer_size)
+            .ok()
+            .filter(|val| !val.is_null())?;
+        Some(self.array_elements_address(items))
+    }
+
+    /// Reads the element at the index given from an instance of a
+    /// `System.Collections.Generic.List<T>`. Returns [`None`] if the index is
+    /// out of bounds or reading the element fails. For reference types the
+    /// elements are pointers, so [`Address64`](crate::Address64) or
+    /// [`Address32`](crate::Address32) need to be used as the type of the
+    /// elements.
+    pub fn read_list_element<T: CheckedBitPattern>(
+        &self,
+        process: &Process,
+        list_instance: Address,
+        index: u32,
+    ) -> Option<T> {
+        if index >= self.list_len(process, list_instance)? {
+            return None;
+        }
+        let offset = (index as u64).checked_mul(mem::size_of::<T>() as u64)?;
+        process
+            .read(self.list_items_address(process, list_instance)? + offset)
+            .ok()
+    }
+
+    /// Reads all the elements stored in an instance of a
+    /// `System.Collections.Generic.List<T>`. For reference types the elements
+    /// are pointers, so [`Address64`](crate::Address64) or
+    /// [`Address32`](crate::Address32) need to be used as the type of the
+    /// elements.
+    #[cfg(feature = "alloc")]
+    pub fn read_list<T: CheckedBitPattern>(
+        &self,
+        process: &Process,
+        list_instance: Address,
+    ) -> Option<alloc::vec::Vec<T>> {
+        let len = self.list_len(process, list_instance)?;
+        if len == 0 {
+            return Some(alloc::vec::Vec::new());
+        }
+        let items = self.list_items_address(process, list_instance)?;
+        process.read_vec(items, len as usize).ok()
+    }
+
+    /// Returns the [Mono version](Version) that the module got attached with.
+    /// This is either the version that got provided or the one that got
+    /// detected automatically.
+    pub const fn version(&self) -> Version {
+        self.version
+    }
+
+    /// Returns the [pointer size](PointerSize) of the game, which got
+    /// detected from the Mono module while attaching. This can be used for
+    /// reading pointer paths manually, so the same code works for both the
+    /// 32-bit and the 64-bit build of a game.
+    pub const fn pointer_size(&self) -> PointerSize {
+        self.pointer_size
+    }
+
+    /// Returns whether the game is a 64-bit process.
+    pub const fn is_64bit(&self) -> bool {
+        matches!(self.pointer_size, PointerSize::Bit64)
+    }
+
     #[inline]
     const fn size_of_ptr(&self) -> u64 {
         self.pointer_size as u64
@@ -207,16 +676,13 @@ struct Assembly {
 }
 
 impl Assembly {
-    fn get_name<const N: usize>(
-        &self,
-        process: &Process,
-        module: &Module,
-    ) -> Result<ArrayCString<N>, Error> {
-        process.read_pointer_path(
-            self.assembly,
-            module.pointer_size,
-            &[module.offsets.monoassembly_aname.into(), 0x0],
-        )
+    fn name_matches(&self, process: &Process, module: &Module, expected: &str) -> bool {
+        process
+            .read_pointer(
+                self.assembly + module.offsets.monoassembly_aname,
+                module.pointer_size,
+            )
+            .is_ok_and(|name| string::c_str_matches(process, name, expected))
     }
 
     fn get_image(&self, process: &Process, module: &Module) -> Option<Image> {
@@ -240,11 +706,25 @@ pub struct Image {
 }
 
 impl Image {
-    /// Iterates over all [.NET classes](struct@Class) in the image.
+    /// Iterates over all [.NET classes](struct@Class) in the image. The
+    /// classes are read lazily while iterating. Classes without a name, such
+    /// as ones that are not fully set up yet, are skipped.
     pub fn classes<'a>(
         &self,
         process: &'a Process,
         module: &'a Module,
+    ) -> impl Iterator<Item = Class> + 'a {
+        self.all_classes(process, module)
+            .filter(|class| class.has_name(process, module))
+    }
+
+    /// Iterates over all [.NET classes](struct@Class) in the image, including
+    /// the ones without a name. The lookups by name don't need to skip those
+    /// upfront, as they fail to match anyway.
+    fn all_classes<'a>(
+        &self,
+        process: &'a Process,
+        module: &'a Module,
     ) -> impl Iterator<Item = Class> + 'a {
         let class_cache_size = process
             .read::<i32>(
@@ -265,42 +745,172 @@ impl Image {
             _ => Err(Error {}),
         };
 
-        (0..class_cache_size.unwrap_or_default()).flat_map(move |i| {
-            let mut table = match table_addr {
-                Ok(table_addr) => process
-                    .read_pointer(
-                        table_addr + (i as u64).wrapping_mul(module.size_of_ptr()),
-                        module.pointer_size,
-                    )
-                    .ok(),
-                _ => None,
-            };
+        let class_cache_size = class_cache_size.unwrap_or_default().max(0) as u64;
+
+        // The buckets of the hash table are usually sparsely populated, so
+        // instead of reading each bucket on its own, we read the bucket heads
+        // in chunks and only follow the chains of the non-empty buckets.
+        (0..class_cache_size)
+            .step_by(BUCKET_CHUNK)
+            .flat_map(move |start| {
+                let len = (class_cache_size - start).min(BUCKET_CHUNK as u64) as usize;
+                let mut heads = [Address::NULL; BUCKET_CHUNK];
+
+                let is_ok = table_addr.as_ref().is_ok_and(|&table_addr| {
+                    process
+                        .read_pointers_into(
+                            table_addr + start.wrapping_mul(module.size_of_ptr()),
+                            module.pointer_size,
+                            &mut heads[..len],
+                        )
+                        .is_ok()
+                });
 
-            iter::from_fn(move || {
-                let class = process.read_pointer(table?, module.pointer_size).ok()?;
+                heads
+                    .into_iter()
+                    .take(if is_ok { len } else { 0 })
+                    .filter(|head| !head.is_null())
+            })
+            .flat_map(move |head| {
+                let mut table = Some(head);
 
-                table = process
-                    .read_pointer(
-                        table? + module.offsets.monoclassdef_next_class_cache,
-                        module.pointer_size,
-                    )
-                    .ok()
-                    .filter(|val| !val.is_null());
+                iter::from_fn(move || {
+                    let class = process.read_pointer(table?, module.pointer_size).ok()?;
 
-                Some(Class { class })
+                    table = process
+                        .read_pointer(
+                            table? + module.offsets.monoclassdef_next_class_cache,
+                            module.pointer_size,
+                        )
+                        .ok()
+                        .filter(|val| !val.is_null());
+
+                    Some(Class { class })
+                })
             })
-        })
+    }
+
+    /// Returns the amount of [.NET classes](struct@Class) stored in the image.
+    /// This can be used as a quick sanity check, as a count of 0 usually means
+    /// that the wrong image got found or the image isn't fully loaded yet.
+    pub fn class_count(&self, process: &Process, module: &Module) -> Option<u32> {
+        process
+            .read(
+                self.image
+                    + module.offsets.monoimage_class_cache
+                    + module.offsets.monointernalhashtable_num_entries,
+            )
+            .ok()
+    }
+
+    /// Returns the [.NET class](struct@Class) located at the given offset from
+    /// the base address of a module. This is the inverse of
+    /// [`Class::rva`](Class::rva) and allows resolving classes that got stored
+    /// by their offset, without having to look them up by their name. The
+    /// offset is only stable within the same build of the game.
+    pub const fn class_at_rva(&self, module_base: Address, rva: u64) -> Option<Class> {
+        match module_base.value().checked_add(rva) {
+            Some(class) => Some(Class {
+                class: Address::new(class),
+            }),
+            None => None,
+        }
     }
 
     /// Tries to find the specified [.NET class](struct@Class) in the image.
     pub fn get_class(&self, process: &Process, module: &Module, class_name: &str) -> Option<Class> {
-        self.classes(process, module).find(|class| {
-            class
-                .get_name::<CSTR>(process, module)
-                .is_ok_and(|name| name.matches(class_name))
+        self.all_classes(process, module)
+            .find(|class| class.name_matches(process, module, class_name))
+    }
+
+    /// Tries to find the [.NET class](struct@Class) with the specified
+    /// metadata token in the image. The token identifies the definition of
+    /// the class within the image. Unlike the names of classes, the tokens
+    /// usually stay the same when a game obfuscates its names.
+    pub fn get_class_by_token(
+        &self,
+        process: &Process,
+        module: &Module,
+        token: u32,
+    ) -> Option<Class> {
+        self.all_classes(process, module)
+            .find(|class| class.get_token(process, module) == Some(token))
+    }
+
+    /// Tries to find the [.NET class](struct@Class) with the specified
+    /// namespace and name in the image. This is useful if multiple classes
+    /// share the same name.
+    pub fn get_class_in_namespace(
+        &self,
+        process: &Process,
+        module: &Module,
+        namespace: &str,
+        class_name: &str,
+    ) -> Option<Class> {
+        self.all_classes(process, module).find(|class| {
+            class.name_matches(process, module, class_name)
+                && class.namespace_matches(process, module, namespace)
         })
     }
 
+    /// Tries to find the [.NET class](struct@Class) with the specified
+    /// namespace and name in the image. This is the `await`able version of
+    /// the [`get_class_in_namespace`](Self::get_class_in_namespace) function,
+    /// yielding back to the runtime between each try.
+    pub async fn wait_get_class_in_namespace(
+        &self,
+        process: &Process,
+        module: &Module,
+        namespace: &str,
+        class_name: &str,
+    ) -> Class {
+        retry(|| self.get_class_in_namespace(process, module, namespace, class_name)).await
+    }
+
+    /// Tries to find the [.NET class](struct@Class) described by the path
+    /// specified in the image. The path is written the way decompilers show
+    /// the names of types, such as `Namespace.ClassName` for a class in a
+    /// namespace and `Outer/Nested` for a class that is nested in another
+    /// class, which can be combined to `Namespace.Outer/Nested`. A path
+    /// without a namespace matches classes in any namespace, just like
+    /// [`get_class`](Self::get_class).
+    pub fn get_class_path(&self, process: &Process, module: &Module, path: &str) -> Option<Class> {
+        let mut segments = path.split('/');
+        let outer = segments.next()?;
+        let (namespace, class_name) = match outer.rsplit_once('.') {
+            Some((namespace, class_name)) => (Some(namespace), class_name),
+            None => (None, outer),
+        };
+
+        let mut class = self.all_classes(process, module).find(|class| {
+            class.name_matches(process, module, class_name)
+                && namespace
+                    .is_none_or(|namespace| class.namespace_matches(process, module, namespace))
+        })?;
+
+        for nested_name in segments {
+            class = self.all_classes(process, module).find(|nested| {
+                nested.name_matches(process, module, nested_name)
+                    && nested.get_declaring_class(process, module) == Some(class)
+            })?;
+        }
+
+        Some(class)
+    }
+
+    /// Tries to find the [.NET class](struct@Class) described by the path
+    /// specified in the image. This is the `await`able version of the
+    /// [`get_class_path`](Self::get_class_path) function, yielding back to
+    /// the runtime between each try.
+    pub async fn wait_get_class_path(
+        &self,
+        process: &Process,
+        module: &Module,
+        path: &str,
+    ) -> Class {
+        retry(|| self.get_class_path(process, module, path)).await
+    }
+
     /// Tries to find the specified [.NET class](struct@Class) in the image.
     /// This is the `await`able version of the [`get_class`](Self::get_class)
     /// function, yielding back to the runtime between each try.
@@ -312,16 +922,42 @@ impl Image {
     ) -> Class {
         retry(|| self.get_class(process, module, class_name)).await
     }
+
+    /// Tries to find the specified [.NET class](struct@Class) in the image.
+    /// This is the `await`able version of the [`get_class`](Self::get_class)
+    /// function, yielding back to the runtime between each try. Unlike
+    /// [`wait_get_class`](Self::wait_get_class), this gives up after
+    /// `max_ticks` tries and returns [`None`] in that case.
+    pub async fn wait_get_class_timeout(
+        &self,
+        process: &Process,
+        module: &Module,
+        class_name: &str,
+        max_ticks: u64,
+    ) -> Option<Class> {
+        retry_timeout(|| self.get_class(process, module, class_name), max_ticks).await
+    }
 }
 
 /// A .NET class that is part of an [`Image`](Image).
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Class {
     class: Address,
 }
 
 impl Class {
-    fn get_name<const N: usize>(
+    /// Returns the offset of the class from the base address of a module. This
+    /// can be used as a key for storing classes, which can later be resolved
+    /// again via [`Image::class_at_rva`]. Returns [`None`] if the class is
+    /// located before the base address.
+    pub const fn rva(&self, module_base: Address) -> Option<u64> {
+        self.class.value().checked_sub(module_base.value())
+    }
+
+    /// Reads the name of the class. Names that don't fit into `N` bytes
+    /// fail to be read. Use [`get_name_string`](Self::get_name_string) for
+    /// reading names of any length.
+    pub fn get_name<const N: usize>(
         &self,
         process: &Process,
         module: &Module,
@@ -336,7 +972,41 @@ impl Class {
         )
     }
 
-    fn get_name_space<const N: usize>(
+    fn name_matches(&self, process: &Process, module: &Module, expected: &str) -> bool {
+        process
+            .read_pointer(
+                self.class + module.offsets.monoclassdef_klass + module.offsets.monoclass_name,
+                module.pointer_size,
+            )
+            .is_ok_and(|name| string::c_str_matches(process, name, expected))
+    }
+
+    fn has_name(&self, process: &Process, module: &Module) -> bool {
+        process
+            .read_pointer(
+                self.class + module.offsets.monoclassdef_klass + module.offsets.monoclass_name,
+                module.pointer_size,
+            )
+            .is_ok_and(|name| !name.is_null())
+    }
+
+    /// Returns the metadata token of the class, which identifies the
+    /// definition of the class within its image.
+    pub fn get_token(&self, process: &Process, module: &Module) -> Option<u32> {
+        process
+            .read(
+                self.class
+                    + module.offsets.monoclassdef_klass
+                    + module.offsets.monoclass_type_token,
+            )
+            .ok()
+    }
+
+    /// Reads the namespace of the class. Namespaces that don't fit into `N`
+    /// bytes fail to be read. Use
+    /// [`get_namespace_string`](Self::get_namespace_string) for reading
+    /// namespaces of any length.
+    pub fn get_namespace<const N: usize>(
         &self,
         process: &Process,
         module: &Module,
@@ -352,6 +1022,54 @@ impl Class {
         )
     }
 
+    fn namespace_matches(&self, process: &Process, module: &Module, expected: &str) -> bool {
+        process
+            .read_pointer(
+                self.class
+                    + module.offsets.monoclassdef_klass
+                    + module.offsets.monoclass_name_space,
+                module.pointer_size,
+            )
+            .is_ok_and(|name| string::c_str_matches(process, name, expected))
+    }
+
+    /// Reads the name of the class, no matter how long it is. Returns
+    /// [`None`] if the name can't be read.
+    #[cfg(feature = "alloc")]
+    pub fn get_name_string(
+        &self,
+        process: &Process,
+        module: &Module,
+    ) -> Option<alloc::string::String> {
+        let name = process
+            .read_pointer(
+                self.class + module.offsets.monoclassdef_klass + module.offsets.monoclass_name,
+                module.pointer_size,
+            )
+            .ok()?;
+        process.read_c_string(name).ok()
+    }
+
+    /// Reads the namespace of the class, no matter how long it is. Returns
+    /// [`None`] if the namespace can't be read. Classes in the global
+    /// namespace have an empty namespace.
+    #[cfg(feature = "alloc")]
+    pub fn get_namespace_string(
+        &self,
+        process: &Process,
+        module: &Module,
+    ) -> Option<alloc::string::String> {
+        let namespace = process
+            .read_pointer(
+                self.class
+                    + module.offsets.monoclassdef_klass
+                    + module.offsets.monoclass_name_space,
+                module.pointer_size,
+            )
+            .ok()?;
+        process.read_c_string(namespace).ok()
+    }
+
     fn fields<'a>(
         &'a self,
         process: &'a Process,
@@ -364,17 +1082,17 @@ impl Class {
             if iter_break {
                 None
             } else if !this_class.class.is_null()
-                && this_class
-                    .get_name::<CSTR>(process, module)
-                    .is_ok_and(|name| !name.matches("Object"))
-                && this_class
-                    .get_name_space::<CSTR>(process, module)
-                    .is_ok_and(|name| !name.matches("UnityEngine"))
+                && !this_class.name_matches(process, module, "Object")
+                && !this_class.namespace_matches(process, module, "UnityEngine")
             {
+                // The count is stored as a 32-bit integer, but no class in
+                // practice comes anywhere close to 16 bits worth of fields. A
+                // larger count means we are reading garbage, which we don't
+                // want to walk off into.
                 let field_count = process
                     .read::<u32>(this_class.class + module.offsets.monoclassdef_field_count)
                     .ok()
-                    .filter(|&val| val != 0);
+                    .filter(|&val| val != 0 && val <= MAX_MEMBER_COUNT);
 
                 let fields = match field_count {
                     Some(_) => process
@@ -413,48 +1131,427 @@ impl Class {
 
     /// Tries to find the offset for a field with the specified name in the class.
     /// If it's a static field, the offset will be from the start of the static
-    /// table.
+    /// table. `const` fields are skipped.
     pub fn get_field_offset(
         &self,
         process: &Process,
         module: &Module,
         field_name: &str,
+    ) -> Option<u32> {
+        self.get_field(process, module, field_name)?
+            .get_offset(process, module)
+    }
+
+    /// Tries to find the [kind of the type](TypeKind) of the field with the
+    /// specified name in the class. This tells whether the field stores its
+    /// value inline, such as an `int`, or whether it stores a reference to
+    /// an object that needs to be dereferenced.
+    pub fn get_field_type(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+    ) -> Option<TypeKind> {
+        self.get_field(process, module, field_name)?
+            .get_type_kind(process, module)
+    }
+
+    /// Tries to find the [.NET class](struct@Class) of the type of the field
+    /// with the specified name in the class. For instantiations of generic
+    /// types, such as `List<Foo>`, this is the generic type definition, such
+    /// as `List<T>`. Returns [`None`] for fields of primitive types, such as
+    /// `int`, and for arrays.
+    pub fn get_field_class(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+    ) -> Option<Class> {
+        self.get_field(process, module, field_name)?
+            .get_class(process, module)
+    }
+
+    /// Tries to find the offset for a field with the specified name in the
+    /// class, just like [`get_field_offset`](Self::get_field_offset). Unlike
+    /// that function, this also considers `const` fields. Their values are
+    /// part of the metadata of the class rather than stored at an offset, so
+    /// this is rarely needed.
+    pub fn get_field_offset_including_literals(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
     ) -> Option<u32> {
         self.fields(process, module)
-            .find(|field| {
-                field
-                    .get_name::<CSTR>(process, module)
-                    .is_ok_and(|name| name.matches(field_name))
-            })?
+            .find(|field| field.name_matches(process, module, field_name))?
             .get_offset(process, module)
     }
 
-    /// Tries to find the address of a static instance of the class based on its
-    /// field name. This waits until the field is not null.
-    pub async fn wait_get_static_instance(
+    /// Iterates over the fields of the class and its parent classes, yielding
+    /// the name and offset of each field. This is useful for dumping the
+    /// layout of a class. `const` fields are skipped, as they aren't stored at
+    /// an offset. Static fields are included, with their offset being
+    /// relative to the static data of the class they are declared in.
+    #[cfg(feature = "alloc")]
+    pub fn field_offsets<'a>(
+        &'a self,
+        process: &'a Process,
+        module: &'a Module,
+    ) -> impl Iterator<Item = (alloc::string::String, u32)> + 'a {
+        self.fields(process, module)
+            .filter(|field| !field.is_literal(process, module))
+            .filter_map(|field| {
+                Some((
+                    field.get_name_string(process, module)?,
+                    field.get_offset(process, module)?,
+                ))
+            })
+    }
+
+    /// Tries to find the field with the specified name in the class. `const`
+    /// fields are skipped, so they can't shadow the actual fields of the
+    /// class.
+    fn get_field(&self, process: &Process, module: &Module, field_name: &str) -> Option<Field> {
+        self.fields(process, module).find(|field| {
+            field.name_matches(process, module, field_name) && !field.is_literal(process, module)
+        })
+    }
+
+    /// Reads the value of the instance field with the specified name from an
+    /// instance of the class. This combines looking up the offset of the
+    /// field and reading it. Returns [`None`] if the field doesn't exist or
+    /// reading it fails. Nothing is cached, so the field is looked up on every
+    /// call.
+    pub fn read_field<T: CheckedBitPattern>(
+        &self,
+        process: &Process,
+        module: &Module,
+        instance: Address,
+        field_name: &str,
+    ) -> Option<T> {
+        let offset = self.get_field_offset(process, module, field_name)?;
+        process.read(instance + offset).ok()
+    }
+
+    /// Tries to find the offsets for multiple fields with the specified names
+    /// in the class. Unlike calling [`get_field_offset`](Self::get_field_offset)
+    /// for each field, this only walks the fields of the class once. The
+    /// offsets are returned in the same order as the names, with [`None`] for
+    /// each field that couldn't be found.
+    pub fn find_field_offsets<const N: usize>(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_names: [&str; N],
+    ) -> [Option<u32>; N] {
+        let mut offsets = [None; N];
+        self.resolve_field_offsets(process, module, &field_names, &mut offsets);
+        offsets
+    }
+
+    /// Tries to find the offsets for any number of fields with the specified
+    /// names in the class. This is the same as
+    /// [`find_field_offsets`](Self::find_field_offsets), but for when the
+    /// names aren't known at compile time. The fields of the class are only
+    /// walked once, no matter how many names are specified.
+    #[cfg(feature = "alloc")]
+    pub fn find_field_offsets_vec(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_names: &[&str],
+    ) -> alloc::vec::Vec<Option<u32>> {
+        let mut offsets = alloc::vec![None; field_names.len()];
+        self.resolve_field_offsets(process, module, field_names, &mut offsets);
+        offsets
+    }
+
+    /// Walks the fields of the class once, storing the offset of each field
+    /// in `field_names` at the same index in `offsets`.
+    fn resolve_field_offsets(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_names: &[&str],
+        offsets: &mut [Option<u32>],
+    ) {
+        let mut remaining = field_names.len();
+        let mut buf = [0; 128];
+
+        for field in self.fields(process, module) {
+            if remaining == 0 {
+                break;
+            }
+            if field.is_literal(process, module) {
+                continue;
+            }
+            // The name of the field is only read once and then compared
+            // against all the names that are still missing. Names that don't
+            // fit into the buffer are compared in the process instead.
+            let name = field.read_name(process, module, &mut buf);
+            for (offset, &field_name) in offsets.iter_mut().zip(field_names) {
+                let is_match = || match name {
+                    Some(name) => name == field_name.as_bytes(),
+                    None => field.name_matches(process, module, field_name),
+                };
+                if offset.is_none() && is_match() {
+                    *offset = field.get_offset(process, module);
+                    if offset.is_some() {
+                        remaining -= 1;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Tries to find the offsets for multiple fields with the specified names
+    /// in the class, walking the fields of the class only once. The offsets
+    /// are returned in the same order as the names. Returns [`None`] if any of
+    /// the fields couldn't be found.
+    pub fn get_field_offsets<const N: usize>(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_names: [&str; N],
+    ) -> Option<[u32; N]> {
+        let offsets = self.find_field_offsets(process, module, field_names);
+        if offsets.iter().any(Option::is_none) {
+            return None;
+        }
+        Some(offsets.map(Option::unwrap_or_default))
+    }
+
+    fn methods<'a>(
+        &'a self,
+        process: &'a Process,
+        module: &'a Module,
+    ) -> impl Iterator<Item = Method> + 'a {
+        let method_count = process
+            .read::<u32>(self.class + module.offsets.monoclassdef_method_count)
+            .ok()
+            .filter(|&val| val != 0 && val <= MAX_MEMBER_COUNT);
+
+        // Mono sets up the methods of a class lazily, so they may not be
+        // available yet.
+        let methods = match method_count {
+            Some(_) => process
+                .read_pointer(
+                    self.class
+                        + module.offsets.monoclassdef_klass
+                        + module.offsets.monoclass_methods,
+                    module.pointer_size,
+                )
+                .ok()
+                .filter(|val| !val.is_null()),
+            _ => None,
+        };
+
+        (0..method_count.unwrap_or_default() as u64).filter_map(move |i| {
+            Some(Method {
+                method: process
+                    .read_pointer(
+                        methods? + i.wrapping_mul(module.size_of_ptr()),
+                        module.pointer_size,
+                    )
+                    .ok()
+                    .filter(|val| !val.is_null())?,
+            })
+        })
+    }
+
+    /// Tries to find a method with the specified name in the class and returns
+    /// information about it. Only the methods declared by the class itself
+    /// are searched, not the ones of its parent classes. If the method is
+    /// overloaded, the first overload is returned.
+    ///
+    /// Mono sets up the methods of a class and their signatures lazily, so
+    /// this may return [`None`] until the game used the method.
+    pub fn get_method_info(
+        &self,
+        process: &Process,
+        module: &Module,
+        method_name: &str,
+    ) -> Option<MethodInfo> {
+        self.methods(process, module)
+            .find(|method| method.name_matches(process, module, method_name))?
+            .get_info(process, module)
+    }
+
+    /// Tries to find a method with the specified name in the class and returns
+    /// the address of the `MonoMethod` describing it. Only the methods
+    /// declared by the class itself are searched. If the method is
+    /// overloaded, the first overload is returned.
+    ///
+    /// Unlike [`il2cpp::Class::get_method`](super::il2cpp::Class::get_method),
+    /// this is not the address of the code of the method. Mono compiles
+    /// methods just in time, so the code may not even exist yet.
+    pub fn get_method_handle(
+        &self,
+        process: &Process,
+        module: &Module,
+        method_name: &str,
+    ) -> Option<Address> {
+        let method = self
+            .methods(process, module)
+            .find(|method| method.name_matches(process, module, method_name))?;
+        Some(method.method)
+    }
+
+    /// Tries to find a method with the specified name in the class and returns
+    /// the address of the `MonoMethod` describing it. This is the `await`able
+    /// version of the [`get_method_handle`](Self::get_method_handle)
+    /// function, yielding back to the runtime between each try.
+    pub async fn wait_get_method_handle(
+        &self,
+        process: &Process,
+        module: &Module,
+        method_name: &str,
+    ) -> Address {
+        retry(|| self.get_method_handle(process, module, method_name)).await
+    }
+
+    /// Returns the size of an instance of the class in bytes, including the
+    /// header of the object.
+    pub fn get_instance_size(&self, process: &Process, module: &Module) -> Option<u32> {
+        process
+            .read::<u32>(
+                self.class
+                    + module.offsets.monoclassdef_klass
+                    + module.offsets.monoclass_instance_size,
+            )
+            .ok()
+            .filter(|&val| val != 0)
+    }
+
+    /// Checks whether the offset of an instance field lies within the size of
+    /// an instance of the class. An offset outside of the instance usually
+    /// means that the offset is stale, such as after an update of the game
+    /// changed the layout of the class. This does not apply to static fields.
+    pub fn validate_field_offset(&self, process: &Process, module: &Module, offset: u64) -> bool {
+        self.get_instance_size(process, module)
+            .is_some_and(|size| offset < size as u64)
+    }
+
+    /// Tries to find the offset for an instance field with the specified name
+    /// in the class, just like [`get_field_offset`](Self::get_field_offset).
+    /// Additionally this returns [`None`] if the offset lies outside of an
+    /// instance of the class, which indicates a mismatch in the layout of the
+    /// class. This does not work for static fields.
+    pub fn get_field_offset_checked(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+    ) -> Option<u32> {
+        self.get_field_offset(process, module, field_name)
+            .filter(|&offset| self.validate_field_offset(process, module, offset as u64))
+    }
+
+    /// Tries to find the address of the value of a static field with the
+    /// specified name. Static fields that are declared by a parent class are
+    /// stored in the static table of that class, so the static table of the
+    /// class declaring the field is used.
+    pub fn get_static_field_address(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+    ) -> Option<Address> {
+        let field = self.get_field(process, module, field_name)?;
+
+        let declaring_class = field.get_parent(process, module).unwrap_or(*self);
+        let static_table = declaring_class.get_static_table(process, module)?;
+
+        Some(static_table + field.get_offset(process, module)?)
+    }
+
+    /// Tries to find the address of a static instance of the class based on its
+    /// field name. This waits until the field is not null.
+    pub async fn wait_get_static_instance(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+    ) -> Address {
+        retry(self.static_instance_reader(process, module, field_name)).await
+    }
+
+    /// Tries to find the address of a static instance of the class based on its
+    /// field name. This waits until the field is not null. Unlike
+    /// [`wait_get_static_instance`](Self::wait_get_static_instance), this gives
+    /// up after `max_ticks` tries and returns [`None`] in that case.
+    pub async fn wait_get_static_instance_timeout(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+        max_ticks: u64,
+    ) -> Option<Address> {
+        retry_timeout(
+            self.static_instance_reader(process, module, field_name),
+            max_ticks,
+        )
+        .await
+    }
+
+    /// Returns a function reading the instance that is stored in the static
+    /// field with the specified name. The address of the field is only looked
+    /// up until it is found, so retrying the function afterwards only repeats
+    /// the read of the instance.
+    fn static_instance_reader<'a>(
+        &'a self,
+        process: &'a Process,
+        module: &'a Module,
+        field_name: &'a str,
+    ) -> impl FnMut() -> Option<Address> + Unpin + 'a {
+        let mut field_address = None;
+        move || {
+            let address = match field_address {
+                Some(address) => address,
+                None => *field_address
+                    .insert(self.get_static_field_address(process, module, field_name)?),
+            };
+            process
+                .read_pointer(address, module.pointer_size)
+                .ok()
+                .filter(|val| !val.is_null())
+        }
+    }
+
+    /// Reads the instance that is stored in a static field with the specified
+    /// name, such as the `instance` field of a singleton. Returns [`None`] if
+    /// the field can't be found or if it is still null, which usually means
+    /// that the instance isn't constructed yet.
+    pub fn read_static_instance(
+        &self,
+        process: &Process,
+        module: &Module,
+        field_name: &str,
+    ) -> Option<Address> {
+        process
+            .read_pointer(
+                self.get_static_field_address(process, module, field_name)?,
+                module.pointer_size,
+            )
+            .ok()
+            .filter(|val| !val.is_null())
+    }
+
+    /// Reads the value of the static field with the specified name. This
+    /// combines looking up the field, the static table of the class declaring
+    /// it and reading the value. Returns [`None`] if the field doesn't exist
+    /// or the static table isn't initialized yet, which is the case until the
+    /// game first uses the class, so this can be retried while the game is
+    /// loading.
+    pub fn read_static_field<T: CheckedBitPattern>(
         &self,
         process: &Process,
         module: &Module,
         field_name: &str,
-    ) -> Address {
-        let static_table = self.wait_get_static_table(process, module).await;
-        let field_offset = self
-            .wait_get_field_offset(process, module, field_name)
-            .await;
-        let singleton_location = static_table + field_offset;
-
-        retry(|| {
-            let addr = process
-                .read_pointer(singleton_location, module.pointer_size)
-                .ok()?;
-
-            if addr.is_null() {
-                None
-            } else {
-                Some(addr)
-            }
-        })
-        .await
+    ) -> Option<T> {
+        process
+            .read(self.get_static_field_address(process, module, field_name)?)
+            .ok()
     }
 
     fn get_static_table_pointer(&self, process: &Process, module: &Module) -> Option<Address> {
@@ -505,7 +1602,39 @@ impl Class {
             .filter(|val| !val.is_null())
     }
 
-    /// Tries to find the parent class.
+    /// Tries to find the class that this class is nested in. This returns
+    /// [`None`] if the class is not a nested class.
+    pub fn get_declaring_class(&self, process: &Process, module: &Module) -> Option<Class> {
+        let class = process
+            .read_pointer(
+                self.class + module.offsets.monoclassdef_klass + module.offsets.monoclass_nested_in,
+                module.pointer_size,
+            )
+            .ok()
+            .filter(|val| !val.is_null())?;
+        Some(Class { class })
+    }
+
+    /// Iterates over the classes that are nested in this class, such as the
+    /// compiler generated classes of coroutines. Mono doesn't store a list of
+    /// the nested classes that is reliably available, so the classes of the
+    /// image that the class is part of are searched for the ones declared by
+    /// this class.
+    pub fn nested_classes<'a>(
+        &self,
+        process: &'a Process,
+        module: &'a Module,
+        image: &Image,
+    ) -> impl Iterator<Item = Class> + 'a {
+        let this = *self;
+        image
+            .classes(process, module)
+            .filter(move |class| class.get_declaring_class(process, module) == Some(this))
+    }
+
+    /// Tries to find the parent class. This returns [`None`] for the root of
+    /// the class hierarchy, which is `System.Object`, as it doesn't have a
+    /// parent.
     pub fn get_parent(&self, process: &Process, module: &Module) -> Option<Class> {
         let parent_addr = process
             .read_pointer(
@@ -523,6 +1652,175 @@ impl Class {
         })
     }
 
+    /// Iterates over the parent classes of the class, starting with the
+    /// direct parent and ending with the root of the class hierarchy. Fields
+    /// declared by parent classes are already found by
+    /// [`get_field_offset`](Self::get_field_offset), so this is mostly useful
+    /// for inspecting the class hierarchy.
+    pub fn parents<'a>(
+        &self,
+        process: &'a Process,
+        module: &'a Module,
+    ) -> impl Iterator<Item = Class> + 'a {
+        iter::successors(self.get_parent(process, module), move |class| {
+            class.get_parent(process, module)
+        })
+    }
+
+    /// Checks whether the class derives from the class specified, by walking
+    /// up the chain of parent classes. A class is not considered a subclass
+    /// of itself.
+    pub fn is_subclass_of(&self, process: &Process, module: &Module, other: &Class) -> bool {
+        iter::successors(self.get_parent(process, module), |class| {
+            class.get_parent(process, module)
+        })
+        .any(|class| class == *other)
+    }
+
+    /// Checks whether the class is an `enum`, which is the case if it derives
+    /// from `System.Enum`.
+    pub fn is_enum(&self, process: &Process, module: &Module) -> bool {
+        self.get_parent(process, module).is_some_and(|parent| {
+            parent.name_matches(process, module, "Enum")
+                && parent.namespace_matches(process, module, "System")
+        })
+    }
+
+    /// Iterates over the values of an `enum` class, in the order they are
+    /// declared in, as pairs of their names and their values. Mono only
+    /// caches the values of the constants of a class once they are needed,
+    /// such as when the game converts a value of the `enum` to a string, so
+    /// only the values that got cached already are yielded. The cache doesn't
+    /// exist with [`Version::V1`], so nothing is yielded there. This also
+    /// yields nothing if the class is not an `enum`.
+    #[cfg(feature = "alloc")]
+    pub fn enum_values<'a>(
+        &'a self,
+        process: &'a Process,
+        module: &'a Module,
+    ) -> impl Iterator<Item = (alloc::string::String, i64)> + 'a {
+        let default_values = self
+            .is_enum(process, module)
+            .then(|| self.get_field_default_values(process, module))
+            .flatten();
+
+        // Unlike the fields of the class, the default values don't include
+        // the ones of the parent classes, so only the class's own fields are
+        // walked.
+        let field_count = process
+            .read::<u32>(self.class + module.offsets.monoclassdef_field_count)
+            .ok()
+            .filter(|&val| val <= MAX_MEMBER_COUNT)
+            .unwrap_or_default();
+        let fields = process.read_pointer(
+            self.class + module.offsets.monoclassdef_klass + module.offsets.monoclass_fields,
+            module.pointer_size,
+        );
+
+        default_values
+            .zip(fields.ok())
+            .into_iter()
+            .flat_map(move |(default_values, fields)| {
+                (0..field_count as u64).filter_map(move |i| {
+                    let field = Field {
+                        field: fields + i * module.offsets.monoclassfieldalignment as u64,
+                    };
+                    if !field.is_literal(process, module) {
+                        return None;
+                    }
+                    let value = read_default_value(
+                        process,
+                        module,
+                        default_values + i * 2 * module.size_of_ptr(),
+                    )?;
+                    Some((field.get_name_string(process, module)?, value))
+                })
+            })
+    }
+
+    /// Reads the array of the default values of the fields of the class, which
+    /// is stored among the infrequently used data of the class. Mono only
+    /// creates the array once the first default value is needed.
+    #[cfg(feature = "alloc")]
+    fn get_field_default_values(&self, process: &Process, module: &Module) -> Option<Address> {
+        // The kind of the entry in the infrequently used data that stores the
+        // default values.
+        const PROP_FIELD_DEF_VALUES: u32 = 7;
+
+        if module.version == Version::V1 {
+            return None;
+        }
+
+        // The infrequently used data is a linked list of entries, each
+        // consisting of a pointer to the next entry and its kind, followed by
+        // its value. There is at most one entry per kind, of which there are
+        // only a few.
+        let first = process
+            .read_pointer(
+                self.class
+                    + module.offsets.monoclassdef_klass
+                    + module.offsets.monoclass_infrequent_data,
+                module.pointer_size,
+            )
+            .ok()?;
+        let entry = iter::successors(Some(first), |&entry| {
+            process.read_pointer(entry, module.pointer_size).ok()
+        })
+        .take(16)
+        .take_while(|entry| !entry.is_null())
+        .find(|&entry| {
+            process
+                .read::<u32>(entry + module.size_of_ptr())
+                .is_ok_and(|kind| kind == PROP_FIELD_DEF_VALUES)
+        })?;
+
+        process
+            .read_pointer(entry + 2 * module.size_of_ptr(), module.pointer_size)
+            .ok()
+            .filter(|val| !val.is_null())
+    }
+
+    /// Tries to find the class of the elements of an array class. This
+    /// returns [`None`] if the class is not an array class.
+    pub fn get_element_class(&self, process: &Process, module: &Module) -> Option<Class> {
+        // Only array classes have a rank. Other classes may still have an
+        // element class, such as enums, which refer to their underlying type.
+        process
+            .read::<u8>(
+                self.class + module.offsets.monoclassdef_klass + module.offsets.monoclass_rank,
+            )
+            .ok()
+            .filter(|&rank| rank != 0)?;
+
+        let class = process
+            .read_pointer(
+                self.class
+                    + module.offsets.monoclassdef_klass
+                    + module.offsets.monoclass_element_class,
+                module.pointer_size,
+            )
+            .ok()
+            .filter(|val| !val.is_null())?;
+
+        Some(Class { class })
+    }
+
+    /// Returns the size of each element in bytes if the class is an array
+    /// class. For reference types this is the size of a pointer, for value
+    /// types it is the size of the value itself. This returns [`None`] if the
+    /// class is not an array class.
+    pub fn get_element_size(&self, process: &Process, module: &Module) -> Option<u32> {
+        self.get_element_class(process, module)?;
+        process
+            .read::<u32>(
+                self.class
+                    + module.offsets.monoclassdef_klass
+                    + module.offsets.monoclass_element_size,
+            )
+            .ok()
+            .filter(|&val| val != 0)
+    }
+
     /// Tries to find a field with the specified name in the class. This returns
     /// the offset of the field from the start of an instance of the class. If
     /// it's a static field, the offset will be from the start of the static
@@ -537,6 +1835,20 @@ impl Class {
         retry(|| self.get_field_offset(process, module, name)).await
     }
 
+    /// Tries to find a field with the specified name in the class. This is the
+    /// `await`able version of the [`get_field_offset`](Self::get_field_offset)
+    /// function. Unlike [`wait_get_field_offset`](Self::wait_get_field_offset),
+    /// this gives up after `max_ticks` tries and returns [`None`] in that case.
+    pub async fn wait_get_field_offset_timeout(
+        &self,
+        process: &Process,
+        module: &Module,
+        name: &str,
+        max_ticks: u64,
+    ) -> Option<u32> {
+        retry_timeout(|| self.get_field_offset(process, module, name), max_ticks).await
+    }
+
     /// Returns the address of the static table of the class. This contains the
     /// values of all the static fields. This is the `await`able version of the
     /// [`get_static_table`](Self::get_static_table) function.
@@ -544,6 +1856,19 @@ impl Class {
         retry(|| self.get_static_table(process, module)).await
     }
 
+    /// Returns the address of the static table of the class. This is the
+    /// `await`able version of the [`get_static_table`](Self::get_static_table)
+    /// function. Unlike [`wait_get_static_table`](Self::wait_get_static_table),
+    /// this gives up after `max_ticks` tries and returns [`None`] in that case.
+    pub async fn wait_get_static_table_timeout(
+        &self,
+        process: &Process,
+        module: &Module,
+        max_ticks: u64,
+    ) -> Option<Address> {
+        retry_timeout(|| self.get_static_table(process, module), max_ticks).await
+    }
+
     /// Tries to find the parent class. This is the `await`able version of the
     /// [`get_parent`](Self::get_parent) function.
     pub async fn wait_get_parent(&self, process: &Process, module: &Module) -> Class {
@@ -551,22 +1876,80 @@ impl Class {
     }
 }
 
+/// Reads the default value of a field of an `enum` from its entry in the
+/// array of default values of a class. The entry consists of the type of the
+/// value, followed by a pointer to the value in the blob heap of the image.
+/// The entry is empty if the value didn't get cached yet.
+#[cfg(feature = "alloc")]
+fn read_default_value(process: &Process, module: &Module, entry: Address) -> Option<i64> {
+    let data = process
+        .read_pointer(entry + module.size_of_ptr(), module.pointer_size)
+        .ok()
+        .filter(|val| !val.is_null())?;
+    let kind = TypeKind::from_raw(process.read::<u8>(entry).ok()?)?;
+
+    // Values in the blob heap are prefixed by their length, which always fits
+    // into a single byte for integers.
+    let value = data + 1;
+    Some(match kind {
+        TypeKind::Boolean | TypeKind::U1 => process.read::<u8>(value).ok()?.into(),
+        TypeKind::I1 => process.read::<i8>(value).ok()?.into(),
+        TypeKind::Char | TypeKind::U2 => process.read::<u16>(value).ok()?.into(),
+        TypeKind::I2 => process.read::<i16>(value).ok()?.into(),
+        TypeKind::U4 => process.read::<u32>(value).ok()?.into(),
+        TypeKind::I4 => process.read::<i32>(value).ok()?.into(),
+        // Values that don't fit are reinterpreted, just like casting them in
+        // C# does.
+        TypeKind::U8 => process.read::<u64>(value).ok()? as i64,
+        TypeKind::I8 => process.read::<i64>(value).ok()?,
+        _ => return None,
+    })
+}
+
 #[derive(Copy, Clone)]
 struct Field {
     field: Address,
 }
 
 impl Field {
-    fn get_name<const N: usize>(
+    fn name_matches(&self, process: &Process, module: &Module, expected: &str) -> bool {
+        process
+            .read_pointer(
+                self.field + module.offsets.monoclassfield_name,
+                module.pointer_size,
+            )
+            .is_ok_and(|name| string::c_str_matches(process, name, expected))
+    }
+
+    /// Reads the name of the field into the buffer given and returns it
+    /// without its null terminator. Returns [`None`] if the name doesn't fit
+    /// into the buffer.
+    fn read_name<'buf>(
         &self,
         process: &Process,
         module: &Module,
-    ) -> Result<ArrayCString<N>, Error> {
-        process.read_pointer_path(
-            self.field,
-            module.pointer_size,
-            &[module.offsets.monoclassfield_name.into(), 0x0],
-        )
+        buf: &'buf mut [u8],
+    ) -> Option<&'buf [u8]> {
+        let name = process
+            .read_pointer(
+                self.field + module.offsets.monoclassfield_name,
+                module.pointer_size,
+            )
+            .ok()?;
+        let len = process.read_partial(name, buf).ok()?;
+        let name = &buf[..len];
+        Some(&name[..name.iter().position(|&b| b == 0)?])
+    }
+
+    #[cfg(feature = "alloc")]
+    fn get_name_string(&self, process: &Process, module: &Module) -> Option<alloc::string::String> {
+        let name = process
+            .read_pointer(
+                self.field + module.offsets.monoclassfield_name,
+                module.pointer_size,
+            )
+            .ok()?;
+        process.read_c_string(name).ok()
     }
 
     fn get_offset(&self, process: &Process, module: &Module) -> Option<u32> {
@@ -574,6 +1957,322 @@ impl Field {
             .read(self.field + module.offsets.monoclassfield_offset)
             .ok()
     }
+
+    /// Checks whether the field is a `const` field, based on the attributes
+    /// of its type. If they can't be read, the field is not considered to be
+    /// a `const` field.
+    fn is_literal(&self, process: &Process, module: &Module) -> bool {
+        const FIELD_ATTRIBUTE_LITERAL: u16 = 0x40;
+
+        process
+            .read_pointer(
+                self.field + module.offsets.monoclassfield_type,
+                module.pointer_size,
+            )
+            .and_then(|ty| process.read::<u16>(ty + module.offsets.monotype_attrs))
+            .is_ok_and(|attrs| attrs & FIELD_ATTRIBUTE_LITERAL != 0)
+    }
+
+    fn get_type_kind(&self, process: &Process, module: &Module) -> Option<TypeKind> {
+        let ty = process
+            .read_pointer(
+                self.field + module.offsets.monoclassfield_type,
+                module.pointer_size,
+            )
+            .ok()?;
+        TypeKind::from_raw(process.read(ty + module.offsets.monotype_type).ok()?)
+    }
+
+    /// Returns the class of the type of the field. Types that refer to a class
+    /// store a pointer to it, while instantiations of generic types store a
+    /// pointer to a `MonoGenericClass`, which starts with the pointer to the
+    /// generic type definition.
+    fn get_class(&self, process: &Process, module: &Module) -> Option<Class> {
+        let ty = process
+            .read_pointer(
+                self.field + module.offsets.monoclassfield_type,
+                module.pointer_size,
+            )
+            .ok()?;
+        let kind = TypeKind::from_raw(process.read(ty + module.offsets.monotype_type).ok()?)?;
+
+        let data = process
+            .read_pointer(ty, module.pointer_size)
+            .ok()
+            .filter(|val| !val.is_null())?;
+        let class = match kind {
+            TypeKind::Class | TypeKind::ValueType => data,
+            TypeKind::GenericInst => process.read_pointer(data, module.pointer_size).ok()?,
+            _ => return None,
+        };
+
+        Some(Class { class }).filter(|class| !class.class.is_null())
+    }
+
+    /// Returns the class that declares the field.
+    fn get_parent(&self, process: &Process, module: &Module) -> Option<Class> {
+        Some(Class {
+            class: process
+                .read_pointer(
+                    self.field + module.offsets.monoclassfield_parent,
+                    module.pointer_size,
+                )
+                .ok()
+                .filter(|val| !val.is_null())?,
+        })
+    }
+}
+
+/// Information about a method of a [.NET class](struct@Class).
+#[derive(Copy, Clone)]
+pub struct MethodInfo {
+    /// The address of the `MonoMethod` describing the method. Mono compiles
+    /// methods just in time, so unlike the `address` of the
+    /// [IL2CPP method info](super::il2cpp::MethodInfo),
+    /// this is not the address of the code of the method.
+    pub handle: Address,
+    /// The amount of parameters of the method, not including `this`.
+    pub param_count: u32,
+    /// The class of the value returned by the method. This is [`None`] for
+    /// methods returning `void` or primitive and generic types.
+    pub return_type: Option<Class>,
+}
+
+#[derive(Copy, Clone)]
+struct Method {
+    method: Address,
+}
+
+impl Method {
+    fn name_matches(&self, process: &Process, module: &Module, expected: &str) -> bool {
+        process
+            .read_pointer(
+                self.method + module.offsets.monomethod_name,
+                module.pointer_size,
+            )
+            .is_ok_and(|name| string::c_str_matches(process, name, expected))
+    }
+
+    fn get_info(&self, process: &Process, module: &Module) -> Option<MethodInfo> {
+        let signature = process
+            .read_pointer(
+                self.method + module.offsets.monomethod_signature,
+                module.pointer_size,
+            )
+            .ok()
+            .filter(|val| !val.is_null())?;
+
+        let param_count = process
+            .read::<u16>(signature + module.offsets.monomethodsignature_param_count)
+            .ok()?;
+
+        let return_type = process
+            .read_pointer(
+                signature + module.offsets.monomethodsignature_ret,
+                module.pointer_size,
+            )
+            .ok()
+            .filter(|val| !val.is_null())
+            .filter(|&ret| {
+                process
+                    .read::<u8>(ret + module.offsets.monotype_type)
+                    .is_ok_and(|ty| {
+                        matches!(
+                            TypeKind::from_raw(ty),
+                            Some(TypeKind::Class | TypeKind::ValueType)
+                        )
+                    })
+            })
+            .and_then(|ret| process.read_pointer(ret, module.pointer_size).ok())
+            .filter(|val| !val.is_null())
+            .map(|class| Class { class });
+
+        Some(MethodInfo {
+            handle: self.method,
+            param_count: param_count as u32,
+            return_type,
+        })
+    }
+}
+
+/// A builder for resolving a [.NET class](struct@Class) and a set of its
+/// fields in one go. This covers the common case of attaching to the
+/// Mono backend, accessing an [image](Image), looking up a class in it and
+/// finding the offsets of some of its fields.
+///
+/// `CAP` is the maximum amount of fields that can be resolved.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn example(process: asr::Process) {
+/// use asr::game_engine::unity::mono::Builder;
+///
+/// let binding = Builder::<2>::new("GameManager")
+///     .field("timer")
+///     .field("level")
+///     .wait_attach(&process)
+///     .await;
+///
+/// let timer_offset = binding.field_offset("timer");
+/// # }
+/// ```
+#[derive(Copy, Clone)]
+pub struct Builder<const CAP: usize> {
+    version: Option<Version>,
+    assembly_name: &'static str,
+    class_name: &'static str,
+    fields: [&'static str; CAP],
+    len: usize,
+}
+
+impl<const CAP: usize> Builder<CAP> {
+    /// Creates a new builder for the class with the name provided. By default
+    /// the class is looked up in the `Assembly-CSharp` [image](Image) and the
+    /// [Mono version](Version) is detected automatically.
+    pub const fn new(class_name: &'static str) -> Self {
+        Self {
+            version: None,
+            assembly_name: "Assembly-CSharp",
+            class_name,
+            fields: [""; CAP],
+            len: 0,
+        }
+    }
+
+    /// Uses the [Mono version](Version) provided instead of detecting it
+    /// automatically.
+    pub const fn version(mut self, version: Version) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Looks up the class in the [image](Image) with the assembly name
+    /// provided instead of `Assembly-CSharp`.
+    pub const fn image(mut self, assembly_name: &'static str) -> Self {
+        self.assembly_name = assembly_name;
+        self
+    }
+
+    /// Adds a field whose offset should be resolved.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if more than `CAP` fields are added.
+    pub const fn field(mut self, field_name: &'static str) -> Self {
+        assert!(
+            self.len < CAP,
+            "More fields were added than `CAP` allows for."
+        );
+        self.fields[self.len] = field_name;
+        self.len += 1;
+        self
+    }
+
+    /// Tries attaching to the game and resolving the class and all of its
+    /// fields. Returns [`None`] if any of the steps fail.
+    pub fn attach(&self, process: &Process) -> Option<Binding<CAP>> {
+        let module = self.attach_module(process)?;
+        let image = module.get_image(process, self.assembly_name)?;
+        let (class, offsets) = self.resolve_class(process, &module, image)?;
+        Some(self.bind(module, image, class, offsets))
+    }
+
+    /// Attaches to the game and resolves the class and all of its fields.
+    ///
+    /// This is the `await`able version of the [`attach`](Self::attach)
+    /// function, yielding back to the runtime between each try. Each step is
+    /// only retried until it succeeds, so waiting for the class to be set up
+    /// doesn't attach to the module and look up the image again every time.
+    pub async fn wait_attach(&self, process: &Process) -> Binding<CAP> {
+        let module = retry(|| self.attach_module(process)).await;
+        let image = retry(|| module.get_image(process, self.assembly_name)).await;
+        let (class, offsets) = retry(|| self.resolve_class(process, &module, image)).await;
+        self.bind(module, image, class, offsets)
+    }
+
+    fn attach_module(&self, process: &Process) -> Option<Module> {
+        match self.version {
+            Some(version) => Module::attach(process, version),
+            _ => Module::attach_auto_detect(process),
+        }
+    }
+
+    /// Looks up the class in the image and resolves the offsets of all the
+    /// fields that got added. Only the fields that got added are looked for,
+    /// so the walk over the fields of the class stops once all of them are
+    /// found.
+    fn resolve_class(
+        &self,
+        process: &Process,
+        module: &Module,
+        image: Image,
+    ) -> Option<(Class, [u32; CAP])> {
+        let class = image.get_class(process, module, self.class_name)?;
+
+        let mut found = [None; CAP];
+        class.resolve_field_offsets(
+            process,
+            module,
+            &self.fields[..self.len],
+            &mut found[..self.len],
+        );
+        let mut offsets = [0; CAP];
+        for (offset, found) in offsets.iter_mut().zip(&found[..self.len]) {
+            *offset = (*found)?;
+        }
+
+        Some((class, offsets))
+    }
+
+    const fn bind(
+        &self,
+        module: Module,
+        image: Image,
+        class: Class,
+        offsets: [u32; CAP],
+    ) -> Binding<CAP> {
+        Binding {
+            module,
+            image,
+            class,
+            fields: self.fields,
+            offsets,
+            len: self.len,
+        }
+    }
+}
+
+/// The result of resolving a [`Builder`]. This holds the [`Module`], the
+/// [`Image`] and the [.NET class](struct@Class) that got resolved, as well as
+/// the offsets of all the fields.
+pub struct Binding<const CAP: usize> {
+    /// The module the class got resolved with.
+    pub module: Module,
+    /// The image the class is part of.
+    pub image: Image,
+    /// The class that got resolved.
+    pub class: Class,
+    fields: [&'static str; CAP],
+    offsets: [u32; CAP],
+    len: usize,
+}
+
+impl<const CAP: usize> Binding<CAP> {
+    /// Returns the offset of the field with the specified name. The field
+    /// needs to have been added to the [`Builder`].
+    pub fn field_offset(&self, field_name: &str) -> Option<u32> {
+        let index = self.fields[..self.len]
+            .iter()
+            .position(|&name| name == field_name)?;
+        Some(self.offsets[index])
+    }
+
+    /// Returns the offsets of all the fields in the order they were added to
+    /// the [`Builder`].
+    pub fn field_offsets(&self) -> &[u32] {
+        &self.offsets[..self.len]
+    }
 }
 
 /// A Mono-specific implementation for automatic pointer path resolution
@@ -692,31 +2391,13 @@ impl<const CAP: usize> UnityPointer<CAP> {
                 _ => {
                     let current_class = match i {
                         0 => starting_class,
-                        _ => {
-                            let class = process
-                                .read_pointer(
-                                    process
-                                        .read_pointer(class_instance, module.pointer_size)
-                                        .ok()
-                                        .filter(|val| !val.is_null())
-                                        .ok_or(Error {})?,
-                                    module.pointer_size,
-                                )
-                                .ok()
-                                .filter(|val| !val.is_null())
-                                .ok_or(Error {})?;
-
-                            Class { class }
-                        }
+                        _ => module
+                            .get_class_of_instance(process, class_instance)
+                            .ok_or(Error {})?,
                     };
 
                     let val = current_class
-                        .fields(process, module)
-                        .find(|field| {
-                            field
-                                .get_name::<CSTR>(process, module)
-                                .is_ok_and(|name| name.matches(self.fields[i]))
-                        })
+                        .get_field(process, module, self.fields[i])
                         .ok_or(Error {})?
                         .get_offset(process, module)
                         .ok_or(Error {})? as u64;
@@ -794,20 +2475,38 @@ struct Offsets {
     monoimage_class_cache: u16,
     monointernalhashtable_table: u8,
     monointernalhashtable_size: u8,
+    monointernalhashtable_num_entries: u8,
     monoclassdef_next_class_cache: u16,
     monoclassdef_klass: u8,
     monoclass_name: u8,
     monoclass_name_space: u8,
+    monoclass_type_token: u8,
     monoclass_fields: u8,
     monoclassdef_field_count: u16,
     monoclass_runtime_info: u8,
+    monoclass_infrequent_data: u8,
     monoclass_vtable_size: u8,
     monoclass_parent: u8,
+    monoclass_nested_in: u8,
+    monoclass_element_class: u8,
+    monoclass_rank: u8,
+    monoclass_element_size: u8,
+    monoclass_instance_size: u8,
+    monoclassfield_type: u8,
     monoclassfield_name: u8,
+    monoclassfield_parent: u8,
     monoclassfield_offset: u8,
     monoclassruntimeinfo_domain_vtables: u8,
     monovtable_vtable: u8,
     monoclassfieldalignment: u8,
+    monoclass_methods: u8,
+    monoclassdef_method_count: u16,
+    monomethod_signature: u8,
+    monomethod_name: u8,
+    monomethodsignature_ret: u8,
+    monomethodsignature_param_count: u8,
+    monotype_attrs: u8,
+    monotype_type: u8,
 }
 
 impl Offsets {
@@ -820,20 +2519,38 @@ impl Offsets {
                     monoimage_class_cache: 0x3D0,
                     monointernalhashtable_table: 0x20,
                     monointernalhashtable_size: 0x18,
+                    monointernalhashtable_num_entries: 0x1C,
                     monoclassdef_next_class_cache: 0x100,
                     monoclassdef_klass: 0x0,
                     monoclass_name: 0x48,
                     monoclass_name_space: 0x50,
+                    monoclass_type_token: 0x58,
                     monoclass_fields: 0xA8,
                     monoclassdef_field_count: 0x94,
                     monoclass_runtime_info: 0xF8,
-                    monoclass_vtable_size: 0x18, // MonoVtable.data
+                    monoclass_infrequent_data: 0x0, // Not available
+                    monoclass_vtable_size: 0x18,    // MonoVtable.data
                     monoclass_parent: 0x30,
+                    monoclass_nested_in: 0x38,
+                    monoclass_element_class: 0x0,
+                    monoclass_rank: 0x1A,
+                    monoclass_element_size: 0x88,
+                    monoclass_instance_size: 0x1C,
+                    monoclassfield_type: 0x0,
                     monoclassfield_name: 0x8,
+                    monoclassfield_parent: 0x10,
                     monoclassfield_offset: 0x18,
                     monoclassruntimeinfo_domain_vtables: 0x8,
                     monovtable_vtable: 0x48,
                     monoclassfieldalignment: 0x20,
+                    monoclass_methods: 0xB0,
+                    monoclassdef_method_count: 0x9C,
+                    monomethod_signature: 0x10,
+                    monomethod_name: 0x18,
+                    monomethodsignature_ret: 0x10,
+                    monomethodsignature_param_count: 0x4,
+                    monotype_attrs: 0x8,
+                    monotype_type: 0xA,
                 }),
                 Version::V2 => Some(&Self {
                     monoassembly_aname: 0x10,
@@ -841,20 +2558,38 @@ impl Offsets {
                     monoimage_class_cache: 0x4C0,
                     monointernalhashtable_table: 0x20,
                     monointernalhashtable_size: 0x18,
+                    monointernalhashtable_num_entries: 0x1C,
                     monoclassdef_next_class_cache: 0x108,
                     monoclassdef_klass: 0x0,
                     monoclass_name: 0x48,
                     monoclass_name_space: 0x50,
+                    monoclass_type_token: 0x58,
                     monoclass_fields: 0x98,
                     monoclassdef_field_count: 0x100,
                     monoclass_runtime_info: 0xD0,
+                    monoclass_infrequent_data: 0xE0,
                     monoclass_vtable_size: 0x5C,
                     monoclass_parent: 0x30,
+                    monoclass_nested_in: 0x38,
+                    monoclass_element_class: 0x0,
+                    monoclass_rank: 0x1A,
+                    monoclass_element_size: 0x90,
+                    monoclass_instance_size: 0x1C,
+                    monoclassfield_type: 0x0,
                     monoclassfield_name: 0x8,
+                    monoclassfield_parent: 0x10,
                     monoclassfield_offset: 0x18,
                     monoclassruntimeinfo_domain_vtables: 0x8,
                     monovtable_vtable: 0x40,
                     monoclassfieldalignment: 0x20,
+                    monoclass_methods: 0xA0,
+                    monoclassdef_method_count: 0xFC,
+                    monomethod_signature: 0x10,
+                    monomethod_name: 0x18,
+                    monomethodsignature_ret: 0x0,
+                    monomethodsignature_param_count: 0x8,
+                    monotype_attrs: 0x8,
+                    monotype_type: 0xA,
                 }),
                 Version::V3 => Some(&Self {
                     monoassembly_aname: 0x10,
@@ -862,20 +2597,38 @@ impl Offsets {
                     monoimage_class_cache: 0x4D0,
                     monointernalhashtable_table: 0x20,
                     monointernalhashtable_size: 0x18,
+                    monointernalhashtable_num_entries: 0x1C,
                     monoclassdef_next_class_cache: 0x108,
                     monoclassdef_klass: 0x0,
                     monoclass_name: 0x48,
                     monoclass_name_space: 0x50,
+                    monoclass_type_token: 0x58,
                     monoclass_fields: 0x98,
                     monoclassdef_field_count: 0x100,
                     monoclass_runtime_info: 0xD0,
+                    monoclass_infrequent_data: 0xE0,
                     monoclass_vtable_size: 0x5C,
                     monoclass_parent: 0x30,
+                    monoclass_nested_in: 0x38,
+                    monoclass_element_class: 0x0,
+                    monoclass_rank: 0x1A,
+                    monoclass_element_size: 0x90,
+                    monoclass_instance_size: 0x1C,
+                    monoclassfield_type: 0x0,
                     monoclassfield_name: 0x8,
+                    monoclassfield_parent: 0x10,
                     monoclassfield_offset: 0x18,
                     monoclassruntimeinfo_domain_vtables: 0x8,
                     monovtable_vtable: 0x48,
                     monoclassfieldalignment: 0x20,
+                    monoclass_methods: 0xA0,
+                    monoclassdef_method_count: 0xFC,
+                    monomethod_signature: 0x10,
+                    monomethod_name: 0x18,
+                    monomethodsignature_ret: 0x0,
+                    monomethodsignature_param_count: 0x8,
+                    monotype_attrs: 0x8,
+                    monotype_type: 0xA,
                 }),
             },
             PointerSize::Bit32 => match version {
@@ -885,20 +2638,38 @@ impl Offsets {
                     monoimage_class_cache: 0x2A0,
                     monointernalhashtable_table: 0x14,
                     monointernalhashtable_size: 0xC,
+                    monointernalhashtable_num_entries: 0x10,
                     monoclassdef_next_class_cache: 0xA8,
                     monoclassdef_klass: 0x0,
                     monoclass_name: 0x30,
                     monoclass_name_space: 0x34,
+                    monoclass_type_token: 0x38,
                     monoclass_fields: 0x74,
                     monoclassdef_field_count: 0x64,
                     monoclass_runtime_info: 0xA4,
-                    monoclass_vtable_size: 0xC, // MonoVtable.data
+                    monoclass_infrequent_data: 0x0, // Not available
+                    monoclass_vtable_size: 0xC,     // MonoVtable.data
                     monoclass_parent: 0x24,
+                    monoclass_nested_in: 0x28,
+                    monoclass_element_class: 0x0,
+                    monoclass_rank: 0xE,
+                    monoclass_element_size: 0x58,
+                    monoclass_instance_size: 0x10,
+                    monoclassfield_type: 0x0,
                     monoclassfield_name: 0x4,
+                    monoclassfield_parent: 0x8,
                     monoclassfield_offset: 0xC,
                     monoclassruntimeinfo_domain_vtables: 0x4,
                     monovtable_vtable: 0x28,
                     monoclassfieldalignment: 0x10,
+                    monoclass_methods: 0x78,
+                    monoclassdef_method_count: 0x6C,
+                    monomethod_signature: 0xC,
+                    monomethod_name: 0x10,
+                    monomethodsignature_ret: 0xC,
+                    monomethodsignature_param_count: 0x4,
+                    monotype_attrs: 0x4,
+                    monotype_type: 0x6,
                 }),
                 Version::V2 => Some(&Self {
                     monoassembly_aname: 0x8,
@@ -906,20 +2677,38 @@ impl Offsets {
                     monoimage_class_cache: 0x354,
                     monointernalhashtable_table: 0x14,
                     monointernalhashtable_size: 0xC,
+                    monointernalhashtable_num_entries: 0x10,
                     monoclassdef_next_class_cache: 0xA8,
                     monoclassdef_klass: 0x0,
                     monoclass_name: 0x2C,
                     monoclass_name_space: 0x30,
+                    monoclass_type_token: 0x34,
                     monoclass_fields: 0x60,
                     monoclassdef_field_count: 0xA4,
                     monoclass_runtime_info: 0x84,
+                    monoclass_infrequent_data: 0x8C,
                     monoclass_vtable_size: 0x38,
                     monoclass_parent: 0x20,
+                    monoclass_nested_in: 0x24,
+                    monoclass_element_class: 0x0,
+                    monoclass_rank: 0xE,
+                    monoclass_element_size: 0x5C,
+                    monoclass_instance_size: 0x10,
+                    monoclassfield_type: 0x0,
                     monoclassfield_name: 0x4,
+                    monoclassfield_parent: 0x8,
                     monoclassfield_offset: 0xC,
                     monoclassruntimeinfo_domain_vtables: 0x4,
                     monovtable_vtable: 0x28,
                     monoclassfieldalignment: 0x10,
+                    monoclass_methods: 0x64,
+                    monoclassdef_method_count: 0xA0,
+                    monomethod_signature: 0xC,
+                    monomethod_name: 0x10,
+                    monomethodsignature_ret: 0x0,
+                    monomethodsignature_param_count: 0x4,
+                    monotype_attrs: 0x4,
+                    monotype_type: 0x6,
                 }),
                 Version::V3 => Some(&Self {
                     monoassembly_aname: 0x8,
@@ -927,20 +2716,38 @@ impl Offsets {
                     monoimage_class_cache: 0x35C,
                     monointernalhashtable_table: 0x14,
                     monointernalhashtable_size: 0xC,
+                    monointernalhashtable_num_entries: 0x10,
                     monoclassdef_next_class_cache: 0xA0,
                     monoclassdef_klass: 0x0,
                     monoclass_name: 0x2C,
                     monoclass_name_space: 0x30,
+                    monoclass_type_token: 0x34,
                     monoclass_fields: 0x60,
                     monoclassdef_field_count: 0x9C,
                     monoclass_runtime_info: 0x7C,
+                    monoclass_infrequent_data: 0x84,
                     monoclass_vtable_size: 0x38,
                     monoclass_parent: 0x20,
+                    monoclass_nested_in: 0x24,
+                    monoclass_element_class: 0x0,
+                    monoclass_rank: 0xE,
+                    monoclass_element_size: 0x5C,
+                    monoclass_instance_size: 0x10,
+                    monoclassfield_type: 0x0,
                     monoclassfield_name: 0x4,
+                    monoclassfield_parent: 0x8,
                     monoclassfield_offset: 0xC,
                     monoclassruntimeinfo_domain_vtables: 0x4,
                     monovtable_vtable: 0x2C,
                     monoclassfieldalignment: 0x10,
+                    monoclass_methods: 0x64,
+                    monoclassdef_method_count: 0x98,
+                    monomethod_signature: 0xC,
+                    monomethod_name: 0x10,
+                    monomethodsignature_ret: 0x0,
+                    monomethodsignature_param_count: 0x4,
+                    monotype_attrs: 0x4,
+                    monotype_type: 0x6,
                 }),
             },
             _ => None,
@@ -948,11 +2755,53 @@ impl Offsets {
     }
 }
 
+// The type token directly follows the pointer to the namespace of the class,
+// so it must not overlap with it, and the rank of the class directly precedes
+// its instance size. This is checked at compile time for every layout. Since
+// V2, the infrequently used data is followed by a single pointer and then the
+// flags, the indices of the first method and field and the method count of
+// the `MonoClassDef`, right before its field count. The element size of array
+// classes directly precedes the fields since V2, while in V1 it precedes the
+// flags and the index of the first field, which precede the field count.
+const _: () = {
+    let versions = [Version::V1, Version::V2, Version::V3];
+    let pointer_sizes = [(PointerSize::Bit64, 8), (PointerSize::Bit32, 4)];
+
+    let mut i = 0;
+    while i < versions.len() {
+        let mut j = 0;
+        while j < pointer_sizes.len() {
+            let (pointer_size, size_of_ptr) = pointer_sizes[j];
+            let Some(offsets) = Offsets::new(versions[i], pointer_size) else {
+                panic!("offsets are missing for a Mono version");
+            };
+            assert!(offsets.monoclass_type_token >= offsets.monoclass_name_space + size_of_ptr);
+            assert!(offsets.monoclass_rank + 2 == offsets.monoclass_instance_size);
+            if matches!(versions[i], Version::V1) {
+                assert!(
+                    offsets.monoclass_element_size as u16 + 12 == offsets.monoclassdef_field_count
+                );
+            } else {
+                assert!(offsets.monoclass_element_size + size_of_ptr == offsets.monoclass_fields);
+                assert!(
+                    offsets.monoclass_infrequent_data as u16 + 2 * size_of_ptr as u16 + 16
+                        == offsets.monoclassdef_field_count
+                );
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+};
+
 /// The version of Mono that was used for the game. These don't correlate to the
 /// Mono version numbers.
 #[derive(Copy, Clone, PartialEq, Hash, Debug)]
 pub enum Version {
-    /// Version 1
+    /// Version 1. This is the Mono runtime shipped as `mono.dll` with older
+    /// versions of Unity. It predates the split of
+    /// `MonoClass` into `MonoClassDef`, so the class cache links the classes
+    /// directly. It has its own layouts for both 32-bit and 64-bit games.
     V1,
     /// Version 2
     V2,
@@ -960,49 +2809,24 @@ pub enum Version {
     V3,
 }
 
-fn detect_version(process: &Process) -> Option<Version> {
+/// Detects the [Mono version](Version) that the game is using, without
+/// attaching to it. This is the same detection that
+/// [`Module::attach_auto_detect`] uses, so it can be used to check the version
+/// before attaching, such as for logging it.
+pub fn detect_version(process: &Process) -> Option<Version> {
     if process.get_module_address("mono.dll").is_ok() {
         return Some(Version::V1);
     }
 
-    let unity_module = {
-        let address = process.get_module_address("UnityPlayer.dll").ok()?;
-        let range = pe::read_size_of_image(process, address)? as u64;
-        (address, range)
-    };
-
-    const SIG_202X: Signature<6> = Signature::new("00 32 30 32 ?? 2E");
-
-    let Some(addr) = SIG_202X.scan_process_range(process, unity_module) else {
-        return Some(Version::V2);
-    };
+    // Attaching only supports the Windows builds of Mono, so there's no point
+    // in reporting a version for any other build.
+    process.get_module_address("mono-2.0-bdwgc.dll").ok()?;
 
-    const ZERO: u8 = b'0';
-    const NINE: u8 = b'9';
-
-    let version_string = process.read::<[u8; 6]>(addr + 1).ok()?;
-
-    let (before, after) = version_string.split_at(version_string.iter().position(|&x| x == b'.')?);
-
-    let mut unity: u32 = 0;
-    for &val in before {
-        match val {
-            ZERO..=NINE => unity = unity * 10 + (val - ZERO) as u32,
-            _ => break,
-        }
-    }
-
-    let mut unity_minor: u32 = 0;
-    for &val in &after[1..] {
-        match val {
-            ZERO..=NINE => unity_minor = unity_minor * 10 + (val - ZERO) as u32,
-            _ => break,
-        }
-    }
+    let unity_module = super::unity_player_range(process)?;
 
-    Some(if (unity == 2021 && unity_minor >= 2) || (unity > 2021) {
-        Version::V3
-    } else {
-        Version::V2
+    Some(match super::read_unity_version(process, unity_module) {
+        // Mono's class layout changed with Unity 2021.2
+        Some((major, minor)) if major > 2021 || (major == 2021 && minor >= 2) => Version::V3,
+        _ => Version::V2,
     })
 }
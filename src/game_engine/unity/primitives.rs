@@ -0,0 +1,154 @@
+//! Support for reading the primitive types of C# from managed memory.
+
+// References:
+// https://github.com/dotnet/runtime/blob/main/src/libraries/System.Private.CoreLib/src/System/Decimal.cs
+// https://github.com/dotnet/runtime/blob/main/src/libraries/System.Private.CoreLib/src/System/DateTime.cs
+// https://github.com/Unity-Technologies/mono/blob/unity-master/mono/metadata/object-internals.h
+
+use bytemuck::CheckedBitPattern;
+use time::{Date, Duration, Month, PrimitiveDateTime, Time};
+
+use crate::{Address, Error, PointerSize, Process};
+
+/// The amount of ticks per second. A tick is 100 nanoseconds.
+const TICKS_PER_SECOND: u64 = 10_000_000;
+/// The ticks of a `DateTime` are stored in the lower 62 bits, while the upper
+/// 2 bits store its kind.
+const TICKS_MASK: u64 = (1 << 62) - 1;
+/// The maximum length of a `string` that [`read_cs_string`] reads. Anything
+/// longer is most likely not a `string` at all.
+#[cfg(feature = "alloc")]
+const MAX_STRING_LENGTH: u32 = 1024;
+/// The powers of 10 that the mantissa of a [`Decimal`] can be divided by,
+/// indexed by its scale.
+const POWERS_OF_TEN: [f64; 29] = [
+    1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15, 1e16,
+    1e17, 1e18, 1e19, 1e20, 1e21, 1e22, 1e23, 1e24, 1e25, 1e26, 1e27, 1e28,
+];
+
+/// Reads a C# `bool` from the process at the address given. A `bool` takes up
+/// a single byte. Any value other than 0 is considered `true`, which matches
+/// how the runtime treats it, so this never fails because of an unexpected
+/// value, unlike reading a Rust [`bool`] directly.
+pub fn read_cs_bool(process: &Process, address: impl Into<Address>) -> Result<bool, Error> {
+    Ok(process.read::<u8>(address)? != 0)
+}
+
+/// Reads a C# `char` from the process at the address given. A `char` is a
+/// single UTF-16 code unit that takes up 2 bytes. Surrogates can't be
+/// represented by a single [`char`], so they are replaced with
+/// [`char::REPLACEMENT_CHARACTER`].
+pub fn read_cs_char(process: &Process, address: impl Into<Address>) -> Result<char, Error> {
+    let unit = process.read::<u16>(address)?;
+    Ok(char::from_u32(unit as u32).unwrap_or(char::REPLACEMENT_CHARACTER))
+}
+
+/// Reads a C# `decimal` from the process at the address given. A `decimal`
+/// takes up 16 bytes and consists of a 96-bit integer, a sign and a scale,
+/// which is the power of 10 that the integer gets divided by.
+pub fn read_cs_decimal(process: &Process, address: impl Into<Address>) -> Result<Decimal, Error> {
+    let [flags, hi, lo, mid] = process.read::<[u32; 4]>(address)?;
+
+    let scale = ((flags >> 16) & 0xFF) as u8;
+    if scale > 28 {
+        return Err(Error {});
+    }
+
+    let mantissa = ((hi as i128) << 64) | ((mid as i128) << 32) | lo as i128;
+    let mantissa = if flags & (1 << 31) != 0 {
+        -mantissa
+    } else {
+        mantissa
+    };
+
+    Ok(Decimal { mantissa, scale })
+}
+
+/// Reads a C# `DateTime` from the process at the address given. A `DateTime`
+/// takes up 8 bytes and stores the amount of ticks of 100 nanoseconds since
+/// January 1st of the year 1. Whether the time is in UTC or local time is not
+/// part of the returned value.
+pub fn read_cs_datetime(
+    process: &Process,
+    address: impl Into<Address>,
+) -> Result<PrimitiveDateTime, Error> {
+    let ticks = process.read::<u64>(address)? & TICKS_MASK;
+
+    let epoch = Date::from_calendar_date(1, Month::January, 1).map_err(|_| Error {})?;
+    let since_epoch = Duration::new(
+        (ticks / TICKS_PER_SECOND) as i64,
+        ((ticks % TICKS_PER_SECOND) * 100) as i32,
+    );
+
+    PrimitiveDateTime::new(epoch, Time::MIDNIGHT)
+        .checked_add(since_epoch)
+        .ok_or(Error {})
+}
+
+/// Reads a C# `string` from the process at the address given. The address is
+/// the one of the `System.String` object itself, such as the value of a
+/// field of type `string`. A `string` stores its length as a 32-bit integer
+/// right after the header of the object, followed by its UTF-16 code units.
+/// Invalid UTF-16 is replaced with [`char::REPLACEMENT_CHARACTER`]. Strings
+/// longer than 1024 characters are considered malformed and fail to be read.
+#[cfg(feature = "alloc")]
+pub fn read_cs_string(
+    process: &Process,
+    address: impl Into<Address>,
+    pointer_size: PointerSize,
+) -> Result<alloc::string::String, Error> {
+    let length_address = address.into() + 2 * pointer_size as u64;
+
+    let length = process.read::<u32>(length_address)?;
+    if length > MAX_STRING_LENGTH {
+        return Err(Error {});
+    }
+
+    let units = process.read_vec::<u16>(length_address + 4u8, length as usize)?;
+    Ok(crate::string::decode_utf16_lossy(&units).collect())
+}
+
+/// Reads a boxed value type from the process at the address given. The
+/// address is the one of the boxed object itself, such as the value of a
+/// field of type `object` that stores an `int` or an `enum`. The value is
+/// stored right after the header of the object, which consists of the vtable
+/// and the monitor, so this skips the header and reads the value that follows.
+pub fn read_cs_boxed<T: CheckedBitPattern>(
+    process: &Process,
+    address: impl Into<Address>,
+    pointer_size: PointerSize,
+) -> Result<T, Error> {
+    process.read(address.into() + 2 * pointer_size as u64)
+}
+
+/// A C# `decimal` read from managed memory via [`read_cs_decimal`]. The value
+/// it represents is the [`mantissa`](Self::mantissa) divided by 10 to the
+/// power of the [`scale`](Self::scale).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    mantissa: i128,
+    scale: u8,
+}
+
+impl Decimal {
+    /// Returns the signed integer of the decimal, before the scale is
+    /// applied.
+    pub const fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    /// Returns the power of 10 that the mantissa gets divided by. This is at
+    /// most 28.
+    pub const fn scale(&self) -> u8 {
+        self.scale
+    }
+
+    /// Converts the decimal into the closest [`f64`]. This may lose
+    /// precision, as a decimal can represent more significant digits than an
+    /// [`f64`].
+    pub fn to_f64(&self) -> f64 {
+        // Dividing once by the correctly rounded power of 10 avoids the
+        // rounding error that dividing by 10 repeatedly would add up.
+        self.mantissa as f64 / POWERS_OF_TEN[self.scale as usize]
+    }
+}
@@ -9,12 +9,13 @@
 use core::{array, iter, mem::MaybeUninit};
 
 use crate::{
-    file_format::pe, future::retry, signature::Signature, string::ArrayCString, Address, Address32,
-    Address64, Error, PointerSize, Process,
+    file_format::pe,
+    future::retry,
+    signature::Signature,
+    string::{self, ArrayCString},
+    Address, Address32, Address64, Error, PointerSize, Process,
 };
 
-const CSTR: usize = 128;
-
 /// The scene manager allows you to easily identify the current scene loaded in
 /// the attached Unity game.
 ///
@@ -47,8 +48,8 @@ impl SceneManager {
         // There are multiple signatures that can be used, depending on the version of Unity
         // used in the target game.
         let base_address: Address = if pointer_size == PointerSize::Bit64 {
-            let addr = SIG_64_BIT.scan_process_range(process, unity_player)? + 7;
-            addr + 0x4 + process.read::<i32>(addr).ok()?
+            let addr = SIG_64_BIT.scan_process_range(process, unity_player)? + 4;
+            process.read_rip_relative(addr, 3, 7).ok()?
         } else if let Some(addr) = SIG_32_1.scan_process_range(process, unity_player) {
             process.read::<Address32>(addr + 5).ok()?.into()
         } else if let Some(addr) = SIG_32_2.scan_process_range(process, unity_player) {
@@ -84,6 +85,17 @@ impl SceneManager {
         retry(|| Self::attach(process)).await
     }
 
+    /// Returns the [pointer size](PointerSize) of the game, as detected from
+    /// the Unity player while attaching.
+    pub const fn pointer_size(&self) -> PointerSize {
+        self.pointer_size
+    }
+
+    /// Returns whether the game is a 64-bit process.
+    pub const fn is_64bit(&self) -> bool {
+        matches!(self.pointer_size, PointerSize::Bit64)
+    }
+
     #[inline]
     const fn size_of_ptr(&self) -> u64 {
         self.pointer_size as u64
@@ -218,10 +230,7 @@ impl SceneManager {
     /// active Unity scene.
     pub fn get_root_game_object(&self, process: &Process, name: &str) -> Result<Transform, Error> {
         self.root_game_objects(process, &self.get_current_scene(process)?)
-            .find(|obj| {
-                obj.get_name::<CSTR>(process, self)
-                    .is_ok_and(|obj_name| obj_name.matches(name))
-            })
+            .find(|obj| obj.name_matches(process, self, name))
             .ok_or(Error {})
     }
 
@@ -233,10 +242,7 @@ impl SceneManager {
         name: &str,
     ) -> Result<Transform, Error> {
         self.root_game_objects(process, &self.get_dont_destroy_on_load_scene())
-            .find(|obj| {
-                obj.get_name::<CSTR>(process, self)
-                    .is_ok_and(|obj_name| obj_name.matches(name))
-            })
+            .find(|obj| obj.name_matches(process, self, name))
             .ok_or(Error {})
     }
 }
@@ -266,6 +272,21 @@ impl Transform {
         )
     }
 
+    fn name_matches(&self, process: &Process, scene_manager: &SceneManager, name: &str) -> bool {
+        process
+            .read_pointer(
+                self.address + scene_manager.offsets.game_object,
+                scene_manager.pointer_size,
+            )
+            .and_then(|game_object| {
+                process.read_pointer(
+                    game_object + scene_manager.offsets.game_object_name,
+                    scene_manager.pointer_size,
+                )
+            })
+            .is_ok_and(|obj_name| string::c_str_matches(process, obj_name, name))
+    }
+
     /// Iterates over the classes referred to in the current `Transform`.
     pub fn classes<'a>(
         &'a self,
@@ -344,20 +365,18 @@ impl Transform {
     ) -> Result<Address, Error> {
         self.classes(process, scene_manager)?
             .find(|&addr| {
-                let val: Result<ArrayCString<CSTR>, Error> = match scene_manager.is_il2cpp {
-                    true => process.read_pointer_path(
-                        addr,
-                        scene_manager.pointer_size,
-                        &[0x0, scene_manager.size_of_ptr().wrapping_mul(2), 0x0],
-                    ),
-                    false => process.read_pointer_path(
-                        addr,
-                        scene_manager.pointer_size,
-                        &[0x0, 0x0, scene_manager.offsets.klass_name as u64, 0x0],
-                    ),
+                let read_pointer =
+                    |address: Address| process.read_pointer(address, scene_manager.pointer_size);
+                let class_name = match scene_manager.is_il2cpp {
+                    true => read_pointer(addr).and_then(|class| {
+                        read_pointer(class + scene_manager.size_of_ptr().wrapping_mul(2))
+                    }),
+                    false => read_pointer(addr)
+                        .and_then(read_pointer)
+                        .and_then(|class| read_pointer(class + scene_manager.offsets.klass_name)),
                 };
 
-                val.is_ok_and(|class_name| class_name.matches(name))
+                class_name.is_ok_and(|class_name| string::c_str_matches(process, class_name, name))
             })
             .ok_or(Error {})
     }
@@ -421,10 +440,7 @@ impl Transform {
         name: &str,
     ) -> Result<Self, Error> {
         self.children(process, scene_manager)?
-            .find(|p| {
-                p.get_name::<CSTR>(process, scene_manager)
-                    .is_ok_and(|obj_name| obj_name.matches(name))
-            })
+            .find(|p| p.name_matches(process, scene_manager, name))
             .ok_or(Error {})
     }
 }
@@ -1,4 +1,10 @@
-use core::{fmt, ops::Add};
+use core::{
+    fmt::{self, Write},
+    mem,
+    ops::Add,
+};
+
+use arrayvec::ArrayString;
 
 use bytemuck::{Pod, Zeroable};
 
@@ -38,6 +44,29 @@ macro_rules! define_addr {
                 Self(self.0.wrapping_add(bytes))
             }
 
+            /// Formats the address as a hexadecimal number prefixed with `0x`
+            /// into a buffer on the stack. The number is padded with zeros to
+            /// the full width of the address, so addresses of the same size
+            /// line up in logs.
+            ///
+            /// # Example
+            ///
+            /// ```no_run
+            #[doc = concat!("# use asr::", stringify!($name), ";")]
+            #[doc = concat!("let address = ", stringify!($name), "::new(0x1234);")]
+            /// asr::print_message(&address.to_hex());
+            /// ```
+            pub fn to_hex(self) -> ArrayString<18> {
+                let mut buf = ArrayString::new();
+                let _ = write!(
+                    buf,
+                    "{:#0width$x}",
+                    self.0,
+                    width = 2 + 2 * mem::size_of::<$inner_u>(),
+                );
+                buf
+            }
+
             /// Offsets the address by the given number of bytes.
             #[inline]
             pub const fn add_signed(self, bytes: $inner_i) -> Self {
@@ -65,6 +94,20 @@ macro_rules! define_addr {
                 fmt::LowerHex::fmt(&self.0, f)
             }
         }
+
+        impl fmt::LowerHex for $name {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::LowerHex::fmt(&self.0, f)
+            }
+        }
+
+        impl fmt::UpperHex for $name {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::UpperHex::fmt(&self.0, f)
+            }
+        }
     };
 }
 
@@ -1,15 +1,22 @@
+use arrayvec::ArrayString;
 use bytemuck::{AnyBitPattern, CheckedBitPattern};
 use core::{
+    iter,
     mem::{self, MaybeUninit},
-    slice,
+    slice, str,
 };
 
-use crate::{Address, Address16, Address32, Address64, PointerSize};
+use crate::{Address, Address16, Address32, Address64, Endian, FromEndian, PointerSize};
 
 use super::{sys, Error, MemoryRange};
 
 pub use super::sys::ProcessId;
 
+/// The amount of reads issued to any process so far. This only exists if the
+/// `read-counter` feature is enabled, so it doesn't cost anything otherwise.
+#[cfg(feature = "read-counter")]
+static READ_COUNT: core::sync::atomic::AtomicU64 = core::sync::atomic::AtomicU64::new(0);
+
 /// A process that the auto splitter is attached to.
 #[repr(transparent)]
 pub struct Process(pub(super) sys::Process);
@@ -235,6 +242,44 @@ impl Process {
         Ok((self.get_module_address(name)?, self.get_module_size(name)?))
     }
 
+    /// Queries which operations the host runtime supports for the process.
+    /// Not every runtime supports the same set of operations, so this can be
+    /// used to degrade gracefully instead of running into errors. The
+    /// capabilities are fixed by the current host interface, so this is the
+    /// same for every process.
+    #[inline]
+    pub const fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            can_write: false,
+            can_enumerate_modules: false,
+            can_enumerate_memory: true,
+        }
+    }
+
+    /// Gets the name, address and size of the main module of the process,
+    /// which is the module of the executable itself. This is useful if the
+    /// name of the executable isn't known in advance. The name needs to fit
+    /// into the capacity specified, otherwise [`None`] is returned.
+    #[inline]
+    pub fn main_module<const N: usize>(&self) -> Option<(ArrayString<N>, Address, u64)> {
+        let mut path = [0u8; 4096];
+        let mut len = path.len();
+        // SAFETY: The process handle is guaranteed to be valid. We provide a
+        // valid pointer and length to the buffer. We check whether the buffer
+        // was successfully filled and only look at the part that got filled.
+        // The path is guaranteed to be valid UTF-8.
+        let path = unsafe {
+            if !sys::process_get_path(self.0, path.as_mut_ptr(), &mut len) {
+                return None;
+            }
+            str::from_utf8_unchecked(path.get(..len)?)
+        };
+
+        let name = path.rsplit('/').next()?;
+        let (address, size) = self.get_module_range(name).ok()?;
+        Some((ArrayString::from(name).ok()?, address, size))
+    }
+
     /// Iterates over all committed (not reserved, not free) memory ranges of the process.
     #[inline]
     pub fn memory_ranges(&self) -> impl DoubleEndedIterator<Item = MemoryRange<'_>> {
@@ -248,7 +293,10 @@ impl Process {
     }
 
     /// Reads a value of the type specified from the process at the address
-    /// given.
+    /// given. The bytes are taken as they are, so integers are only correct
+    /// if the [endianness](Self::endian) of the process matches the one of
+    /// the auto splitter. Use [`read_endian`](Self::read_endian) for reads
+    /// that need to honor the endianness of the process.
     #[inline]
     pub fn read<T: CheckedBitPattern>(&self, address: impl Into<Address>) -> Result<T, Error> {
         // SAFETY: The process handle is guaranteed to be valid. We provide a
@@ -270,6 +318,42 @@ impl Process {
         }
     }
 
+    /// Reads a value of the type specified from the process at the given
+    /// offset relative to the base address of the module with the name
+    /// provided. This is useful for reading global variables that are stored
+    /// at a fixed offset from the start of a module.
+    pub fn read_at_offset<T: CheckedBitPattern>(
+        &self,
+        module: &str,
+        offset: u64,
+    ) -> Result<T, ModuleReadError> {
+        let base = self
+            .get_module_address(module)
+            .map_err(|_| ModuleReadError::ModuleNotLoaded)?;
+        Ok(self.read(base + offset)?)
+    }
+
+    /// Returns the amount of reads that were issued to the host since the
+    /// auto splitter started or since the last call to
+    /// [`reset_counters`](Self::reset_counters). Every read counts once, no
+    /// matter how many bytes it reads, and failed reads count as well. The
+    /// counter is shared by all processes. This is meant for measuring how
+    /// many reads attaching or a single update of the auto splitter issues.
+    /// Only available with the `read-counter` feature.
+    #[cfg(feature = "read-counter")]
+    #[inline]
+    pub fn read_count(&self) -> u64 {
+        READ_COUNT.load(core::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Resets the counters returned by [`read_count`](Self::read_count) back
+    /// to zero. Only available with the `read-counter` feature.
+    #[cfg(feature = "read-counter")]
+    #[inline]
+    pub fn reset_counters(&self) {
+        READ_COUNT.store(0, core::sync::atomic::Ordering::Relaxed);
+    }
+
     /// Reads a range of bytes from the process at the address given into the
     /// buffer provided.
     #[inline]
@@ -279,6 +363,8 @@ impl Process {
         // handling afterwards.
         unsafe {
             let buf_len = buf.len();
+            #[cfg(feature = "read-counter")]
+            READ_COUNT.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
             if sys::process_read(self.0, address.into(), buf.as_mut_ptr(), buf_len) {
                 Ok(())
             } else {
@@ -287,6 +373,105 @@ impl Process {
         }
     }
 
+    /// Reads `len` bytes from the process at the address given into the start
+    /// of the buffer provided and returns the part of the buffer that got
+    /// filled. Unlike [`read`](Self::read), the amount of bytes is only known
+    /// at runtime. An error is returned if the buffer is smaller than `len`.
+    pub fn read_bytes<'buf>(
+        &self,
+        address: impl Into<Address>,
+        len: usize,
+        out: &'buf mut [u8],
+    ) -> Result<&'buf [u8], Error> {
+        let buf = out.get_mut(..len).ok_or(Error {})?;
+        self.read_into_buf(address, buf)?;
+        Ok(buf)
+    }
+
+    /// Reads a range of bytes from the process at the address given into the
+    /// buffer provided. Unlike [`read_into_buf`](Self::read_into_buf), this
+    /// doesn't fail if only the start of the range is readable, such as when
+    /// the range crosses into a page that is not mapped. Instead the amount of
+    /// bytes that were successfully read from the start of the range is
+    /// returned. Memory is read in whole pages, so the amount is accurate to
+    /// the granularity of a page. An error is returned if not even the first
+    /// byte could be read.
+    pub fn read_partial(
+        &self,
+        address: impl Into<Address>,
+        buf: &mut [u8],
+    ) -> Result<usize, Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let address = address.into();
+        if self.read_into_buf(address, buf).is_ok() {
+            return Ok(buf.len());
+        }
+
+        // Pages are either readable in full or not at all, so we read up to
+        // the next page boundary at a time.
+        let mut read = 0;
+        for (offset, len) in page_chunks(address, buf.len()) {
+            if self
+                .read_into_buf(address + offset as u64, &mut buf[offset..offset + len])
+                .is_err()
+            {
+                break;
+            }
+            read += len;
+        }
+
+        if read == 0 {
+            Err(Error {})
+        } else {
+            Ok(read)
+        }
+    }
+
+    /// Reads a null-terminated string from the process at the address given.
+    /// Unlike reading an [`ArrayCString`](crate::string::ArrayCString), the
+    /// length of the string is not limited, as it is read until the null
+    /// terminator is found. Invalid UTF-8 sequences are replaced with
+    /// [`char::REPLACEMENT_CHARACTER`].
+    #[cfg(feature = "alloc")]
+    pub fn read_c_string(
+        &self,
+        address: impl Into<Address>,
+    ) -> Result<alloc::string::String, Error> {
+        let bytes = self.read_c_string_bytes(address)?;
+        Ok(match alloc::string::String::from_utf8(bytes) {
+            Ok(string) => string,
+            Err(err) => alloc::string::String::from_utf8_lossy(err.as_bytes()).into_owned(),
+        })
+    }
+
+    /// Reads the bytes of a null-terminated string from the process at the
+    /// address given, without the null terminator. The string is read in
+    /// chunks until the null terminator is found, so its length is not
+    /// limited. The bytes are not required to be valid UTF-8.
+    #[cfg(feature = "alloc")]
+    pub fn read_c_string_bytes(
+        &self,
+        address: impl Into<Address>,
+    ) -> Result<alloc::vec::Vec<u8>, Error> {
+        let mut address = address.into();
+        let mut bytes = alloc::vec::Vec::new();
+        let mut buf = [0; 128];
+
+        loop {
+            let len = self.read_partial(address, &mut buf)?;
+            let chunk = &buf[..len];
+            if let Some(end) = chunk.iter().position(|&b| b == 0) {
+                bytes.extend_from_slice(&chunk[..end]);
+                return Ok(bytes);
+            }
+            bytes.extend_from_slice(chunk);
+            address = address + len as u64;
+        }
+    }
+
     /// Reads a range of bytes from the process at the address given into the
     /// buffer provided. The buffer does not need to be initialized. After the
     /// buffer successfully got filled, the initialized buffer is returned.
@@ -302,6 +487,8 @@ impl Process {
         // afterwards, so we can safely return an u8 slice of it.
         unsafe {
             let buf_len = buf.len();
+            #[cfg(feature = "read-counter")]
+            READ_COUNT.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
             if sys::process_read(self.0, address.into(), buf.as_mut_ptr().cast(), buf_len) {
                 Ok(slice::from_raw_parts_mut(buf.as_mut_ptr().cast(), buf_len))
             } else {
@@ -414,19 +601,119 @@ impl Process {
         Ok(buf)
     }
 
-    /// Reads a pointer address from the process at the address given.
+    /// Returns the endianness of the process. Only values read with
+    /// [`read_endian`](Self::read_endian) and pointers read with
+    /// [`read_pointer`](Self::read_pointer) and
+    /// [`read_pointers_into`](Self::read_pointers_into) are interpreted
+    /// accordingly. [`read`](Self::read) and the other reads work on any
+    /// plain data type, such as structs and arrays, whose bytes can't be
+    /// swapped generically, so they return the bytes as they are. The runtime
+    /// currently only supports processes that are little endian, which the
+    /// auto splitter itself is as well, so this makes no difference yet.
+    #[inline]
+    pub const fn endian(&self) -> Endian {
+        Endian::Little
+    }
+
+    /// Reads a value of the type specified from the process at the address
+    /// given and converts it from the endianness provided.
+    #[inline]
+    pub fn read_endian<T: CheckedBitPattern + FromEndian>(
+        &self,
+        address: impl Into<Address>,
+        endian: Endian,
+    ) -> Result<T, Error> {
+        Ok(self.read::<T>(address)?.from_endian(endian))
+    }
+
+    /// Reads a pointer address from the process at the address given. The
+    /// pointer is interpreted according to the [endianness](Self::endian) of
+    /// the process.
     pub fn read_pointer(
         &self,
         address: impl Into<Address>,
         pointer_size: PointerSize,
     ) -> Result<Address, Error> {
+        let endian = self.endian();
         Ok(match pointer_size {
-            PointerSize::Bit16 => self.read::<Address16>(address)?.into(),
-            PointerSize::Bit32 => self.read::<Address32>(address)?.into(),
-            PointerSize::Bit64 => self.read::<Address64>(address)?.into(),
+            PointerSize::Bit16 => self.read_endian::<Address16>(address, endian)?.into(),
+            PointerSize::Bit32 => self.read_endian::<Address32>(address, endian)?.into(),
+            PointerSize::Bit64 => self.read_endian::<Address64>(address, endian)?.into(),
         })
     }
 
+    /// Reads an array of pointer addresses from the process at the address
+    /// given into the buffer provided. This is a lot faster than reading each
+    /// pointer individually, as the pointers are read in bulk.
+    pub fn read_pointers_into(
+        &self,
+        address: impl Into<Address>,
+        pointer_size: PointerSize,
+        buf: &mut [Address],
+    ) -> Result<(), Error> {
+        const CHUNK: usize = 128;
+
+        let endian = self.endian();
+        let mut address = address.into();
+
+        for chunk in buf.chunks_mut(CHUNK) {
+            match pointer_size {
+                PointerSize::Bit16 => {
+                    let raw = &mut [Address16::NULL; CHUNK][..chunk.len()];
+                    self.read_into_slice(address, raw)?;
+                    for (dst, src) in chunk.iter_mut().zip(&*raw) {
+                        *dst = src.from_endian(endian).into();
+                    }
+                }
+                PointerSize::Bit32 => {
+                    let raw = &mut [Address32::NULL; CHUNK][..chunk.len()];
+                    self.read_into_slice(address, raw)?;
+                    for (dst, src) in chunk.iter_mut().zip(&*raw) {
+                        *dst = src.from_endian(endian).into();
+                    }
+                }
+                PointerSize::Bit64 => {
+                    let raw = &mut [Address64::NULL; CHUNK][..chunk.len()];
+                    self.read_into_slice(address, raw)?;
+                    for (dst, src) in chunk.iter_mut().zip(&*raw) {
+                        *dst = src.from_endian(endian).into();
+                    }
+                }
+            }
+            address = address + (chunk.len() as u64).wrapping_mul(pointer_size as u64);
+        }
+
+        Ok(())
+    }
+
+    /// Resolves the target address of an instruction that uses a 32-bit
+    /// displacement relative to the instruction pointer, such as
+    /// `mov rax, [rip + disp]` on x86-64. The displacement is read at
+    /// `disp_offset` bytes from the start of the instruction. As the
+    /// displacement is relative to the end of the instruction, the length of
+    /// the instruction needs to be provided as well.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use asr::{Address, Process};
+    /// # let process: Process = todo!();
+    /// # let instruction = Address::NULL;
+    /// // 48 8B 05 ?? ?? ?? ?? | mov rax, [rip + disp]
+    /// let target = process.read_rip_relative(instruction, 3, 7);
+    /// ```
+    #[inline]
+    pub fn read_rip_relative(
+        &self,
+        instr_addr: impl Into<Address>,
+        disp_offset: u8,
+        instr_len: u8,
+    ) -> Result<Address, Error> {
+        let instr_addr = instr_addr.into();
+        let disp = self.read::<i32>(instr_addr + disp_offset)?;
+        Ok(instr_addr + instr_len + disp)
+    }
+
     /// Follows a path of pointers from the address given and reads a value of
     /// the type specified from the process at the end of the pointer path.
     pub fn read_pointer_path<T: CheckedBitPattern>(
@@ -442,4 +729,133 @@ impl Process {
         }
         self.read(address + last)
     }
+
+    /// Follows a path of pointers from the address given and reads a value of
+    /// the type specified from the process at the end of the pointer path,
+    /// just like [`read_pointer_path`](Self::read_pointer_path). Unlike that
+    /// function, every pointer along the path is checked for being null. This
+    /// allows distinguishing a path that is not available yet, such as a
+    /// field that didn't get assigned yet, from a path that is wrong.
+    pub fn read_pointer_path_checked<T: CheckedBitPattern>(
+        &self,
+        address: impl Into<Address>,
+        pointer_size: PointerSize,
+        path: &[u64],
+    ) -> Result<T, PointerPathError> {
+        let mut address = address.into();
+        let (&last, path) = path.split_last().ok_or(Error {})?;
+        for (hop, &offset) in path.iter().enumerate() {
+            address = self.read_pointer(address + offset, pointer_size)?;
+            if address.is_null() {
+                return Err(PointerPathError::NullInChain(hop));
+            }
+        }
+        Ok(self.read(address + last)?)
+    }
+}
+
+/// An error returned when following a path of pointers via
+/// [`Process::read_pointer_path_checked`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PointerPathError {
+    /// The pointer read at the given index of the path is null. The value is
+    /// likely not available yet.
+    NullInChain(usize),
+    /// Reading from the process failed.
+    Read(Error),
+}
+
+impl From<Error> for PointerPathError {
+    #[inline]
+    fn from(error: Error) -> Self {
+        Self::Read(error)
+    }
+}
+
+/// An error returned when reading from a process relative to a module.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ModuleReadError {
+    /// The module is not loaded into the process.
+    ModuleNotLoaded,
+    /// The module is loaded, but reading from it failed.
+    Read(Error),
+}
+
+impl From<Error> for ModuleReadError {
+    #[inline]
+    fn from(error: Error) -> Self {
+        Self::Read(error)
+    }
+}
+
+/// Splits the range of `len` bytes starting at the address given into the
+/// parts that each lie within a single page. The offset of each part from the
+/// start of the range and its length are yielded.
+fn page_chunks(address: Address, len: usize) -> impl Iterator<Item = (usize, usize)> {
+    const PAGE_SIZE: u64 = 4 << 10;
+
+    let mut offset = 0;
+    iter::from_fn(move || {
+        if offset >= len {
+            return None;
+        }
+        let current = address.value().wrapping_add(offset as u64);
+        let to_boundary = PAGE_SIZE - (current & (PAGE_SIZE - 1));
+        let chunk = (offset, (to_boundary as usize).min(len - offset));
+        offset += chunk.1;
+        Some(chunk)
+    })
+}
+
+/// The operations that the host runtime supports for a [`Process`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Capabilities {
+    /// Whether the memory of the process can be written to. The current host
+    /// interface doesn't provide a way to write to a process, so this is
+    /// always `false`.
+    pub can_write: bool,
+    /// Whether the modules loaded into the process can be enumerated. The
+    /// current host interface only allows looking up modules by their name,
+    /// so this is always `false`.
+    pub can_enumerate_modules: bool,
+    /// Whether the memory ranges of the process can be enumerated via
+    /// [`Process::memory_ranges`]. This is part of the host interface, so
+    /// this is always `true`.
+    pub can_enumerate_memory: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::page_chunks;
+    use crate::Address;
+
+    #[test]
+    fn page_chunks_unaligned_start() {
+        assert!(page_chunks(Address::new(0x1FF0), 0x2020).eq([
+            (0, 0x10),
+            (0x10, 0x1000),
+            (0x1010, 0x1000),
+            (0x2010, 0x10),
+        ]));
+    }
+
+    #[test]
+    fn page_chunks_ending_on_page_boundary() {
+        assert!(page_chunks(Address::new(0x1800), 0x800).eq([(0, 0x800)]));
+        assert!(page_chunks(Address::new(0x1800), 0x1800).eq([(0, 0x800), (0x800, 0x1000)]));
+    }
+
+    #[test]
+    fn page_chunks_aligned_start() {
+        assert!(page_chunks(Address::new(0x2000), 0x1000).eq([(0, 0x1000)]));
+        assert!(page_chunks(Address::new(0x2000), 0x1001).eq([(0, 0x1000), (0x1000, 1)]));
+    }
+
+    #[test]
+    fn page_chunks_empty() {
+        assert!(page_chunks(Address::new(0x1234), 0).eq([]));
+    }
 }
@@ -1,13 +1,20 @@
 //! Support for finding patterns in a process's memory.
 
-use core::mem::{self, MaybeUninit};
+use core::{
+    array, iter,
+    mem::{self, MaybeUninit},
+};
 
 use bytemuck::AnyBitPattern;
 
-use crate::{Address, Process};
+use crate::{file_format::pe, Address, Process};
 
 type Offset = u8;
 
+/// The skip offset below which signatures with wildcards are scanned by
+/// searching for a single known byte first.
+const PREFILTER_MAX_SKIP: Offset = 16;
+
 /// A signature that can be used to find a pattern in a process. It is
 /// recommended to store this in a `static` or `const` variable to ensure that
 /// the signature is parsed at compile time, which enables the code to be
@@ -66,6 +73,47 @@ const fn contains(mut bytes: &[u8], search_byte: u8) -> bool {
     false
 }
 
+/// Determines the amount of bytes the signature string given describes. This
+/// can be used to declare the length `N` of a [`Signature`] without having to
+/// count the bytes by hand.
+///
+/// # Panics
+///
+/// This function panics if the signature contains characters other than
+/// hexadecimal digits, `?` and whitespace, or if the amount of nibbles is odd.
+/// If used in a `static` or `const` context, these are compile time errors.
+///
+/// # Example
+///
+/// ```
+/// # use asr::signature::{byte_len, Signature};
+/// const PATTERN: &str = "48 8B 0D ?? ?? ?? ?? 48 85 C9";
+/// static SIG: Signature<{ byte_len(PATTERN) }> = Signature::new(PATTERN);
+/// ```
+pub const fn byte_len(signature: &str) -> usize {
+    let mut parser = Parser {
+        bytes: signature.as_bytes(),
+    };
+    let mut len = 0;
+
+    loop {
+        let (a, next) = parser.next();
+        parser = next;
+        let (b, next) = parser.next();
+        parser = next;
+        let (Some(_), Some(_)) = (a, b) else {
+            assert!(
+                a.is_none(),
+                "The signature contains a lone nibble. Every byte needs to consist of two nibbles."
+            );
+            break;
+        };
+        len += 1;
+    }
+
+    len
+}
+
 impl<const N: usize> Signature<N> {
     /// Creates a new signature from a string. The string must be a hexadecimal
     /// string with `?` as wildcard. It is recommended to store this in a
@@ -74,8 +122,13 @@ impl<const N: usize> Signature<N> {
     ///
     /// # Panics
     ///
-    /// This function panics if the signature is invalid. It also panics if the
-    /// signature is longer than 255 bytes.
+    /// This function panics if the signature is invalid, such as when it
+    /// contains characters that are not hexadecimal digits, `?` or
+    /// whitespace, or if a byte consists of a single nibble. It also panics if
+    /// the signature is longer than 255 bytes or if the amount of bytes in the
+    /// signature does not match `N`. Use [`byte_len`] to determine `N` if you
+    /// don't want to count the bytes by hand. If the signature is stored in a
+    /// `static` or `const` variable, these are compile time errors.
     ///
     /// # Example
     ///
@@ -88,6 +141,16 @@ impl<const N: usize> Signature<N> {
         // or longer.
         assert!(N > 0 && N < 256);
 
+        let len = byte_len(signature);
+        assert!(
+            len <= N,
+            "The signature contains more bytes than `N` allows for. Use `byte_len` to determine `N`."
+        );
+        assert!(
+            len >= N,
+            "The signature contains fewer bytes than `N` requires. Use `byte_len` to determine `N`."
+        );
+
         let mut parser = Parser {
             bytes: signature.as_bytes(),
         };
@@ -109,41 +172,8 @@ impl<const N: usize> Signature<N> {
                 mask[i] = mask_byte;
                 i += 1;
             }
-            assert!(i == N);
-
-            let mut skip_offsets = [0; 256];
-
-            let mut unknown = 0;
-            let end = N - 1;
-            let mut i = 0;
-            while i < end {
-                let byte = needle[i];
-                let mask = mask[i];
-                if mask == 0xFF {
-                    skip_offsets[byte as usize] = (end - i) as Offset;
-                } else {
-                    unknown = (end - i) as Offset;
-                }
-                i += 1;
-            }
 
-            if unknown == 0 {
-                unknown = N as Offset;
-            }
-
-            i = 0;
-            while i < skip_offsets.len() {
-                if unknown < skip_offsets[i] || skip_offsets[i] == 0 {
-                    skip_offsets[i] = unknown;
-                }
-                i += 1;
-            }
-
-            Self::Complex {
-                needle,
-                mask,
-                skip_offsets,
-            }
+            Self::complex(needle, mask)
         } else {
             let mut needle = [0; N];
             let mut i = 0;
@@ -158,13 +188,93 @@ impl<const N: usize> Signature<N> {
                 needle[i] = sig_byte;
                 i += 1;
             }
-            assert!(i == N);
 
             Self::Simple(needle)
         }
     }
 
-    fn scan(&self, haystack: &[u8]) -> Option<usize> {
+    /// Creates a new signature from bytes that are only known at runtime, such
+    /// as bytes read from the process. Every byte whose entry in the mask is
+    /// `false` is a wildcard. Prefer [`new`](Self::new) for signatures that
+    /// are known at compile time, as those get parsed at compile time. Returns
+    /// [`None`] if the bytes or the mask don't consist of exactly `N`
+    /// entries, or if `N` is 0 or 256 and above.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use asr::signature::Signature;
+    /// let sig = Signature::<3>::from_bytes(&[0x48, 0x00, 0x05], &[true, false, true]).unwrap();
+    /// assert_eq!(sig.scan_slice(&[0x00, 0x48, 0x8B, 0x05]), Some(1));
+    /// ```
+    pub fn from_bytes(bytes: &[u8], mask: &[bool]) -> Option<Self> {
+        if N == 0 || N >= 256 {
+            return None;
+        }
+        let needle: [u8; N] = bytes.try_into().ok()?;
+        let mask: &[bool; N] = mask.try_into().ok()?;
+
+        if mask.iter().all(|&known| known) {
+            return Some(Self::Simple(needle));
+        }
+
+        let mask = mask.map(|known| if known { 0xFF } else { 0x00 });
+        Some(Self::complex(array::from_fn(|i| needle[i] & mask[i]), mask))
+    }
+
+    /// Creates a signature with wildcards. The bytes of the needle that are
+    /// wildcards need to be zeroed out already.
+    const fn complex(needle: [u8; N], mask: [u8; N]) -> Self {
+        let mut skip_offsets = [0; 256];
+
+        let mut unknown = 0;
+        let end = N - 1;
+        let mut i = 0;
+        while i < end {
+            let byte = needle[i];
+            let mask = mask[i];
+            if mask == 0xFF {
+                skip_offsets[byte as usize] = (end - i) as Offset;
+            } else {
+                unknown = (end - i) as Offset;
+            }
+            i += 1;
+        }
+
+        if unknown == 0 {
+            unknown = N as Offset;
+        }
+
+        i = 0;
+        while i < skip_offsets.len() {
+            if unknown < skip_offsets[i] || skip_offsets[i] == 0 {
+                skip_offsets[i] = unknown;
+            }
+            i += 1;
+        }
+
+        Self::Complex {
+            needle,
+            mask,
+            skip_offsets,
+        }
+    }
+
+    /// Scans a slice of bytes for the signature. This is useful if the memory
+    /// got read into a buffer already, such as a cached copy of a module's
+    /// code section, as it doesn't need to be read from the process again. If
+    /// the signature is found, the offset of the start of the signature within
+    /// the slice is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use asr::signature::Signature;
+    /// static SIG: Signature<3> = Signature::new("48 ?? 05");
+    /// assert_eq!(SIG.scan_slice(&[0x00, 0x48, 0x8B, 0x05]), Some(1));
+    /// assert_eq!(SIG.scan_slice(&[0x48, 0x8B, 0x06]), None);
+    /// ```
+    pub fn scan_slice(&self, haystack: &[u8]) -> Option<usize> {
         match self {
             Signature::Simple(needle) => memchr::memmem::find(haystack, needle),
             Signature::Complex {
@@ -172,49 +282,306 @@ impl<const N: usize> Signature<N> {
                 mask,
                 skip_offsets,
             } => {
-                let mut current = 0;
-                let end = N - 1;
-                while let Some(scan) = strip_pod::<[u8; N]>(&mut &haystack[current..]) {
-                    if matches(scan, needle, mask) {
-                        return Some(current);
+                // Signatures with a wildcard close to their end can only skip
+                // ahead by a few bytes at a time. In that case we instead
+                // search for a byte of the signature that is fully known via
+                // `memchr`, which is vectorized, and only compare the whole
+                // signature where that byte occurs.
+                if skip_offsets
+                    .iter()
+                    .all(|&offset| offset < PREFILTER_MAX_SKIP)
+                {
+                    if let Some(anchor) = mask.iter().position(|&mask| mask == 0xFF) {
+                        return scan_prefiltered(haystack, needle, mask, anchor);
                     }
-                    let offset = skip_offsets[scan[end] as usize];
-                    current += offset as usize;
                 }
-                None
+
+                scan_skip_table(haystack, needle, mask, skip_offsets)
             }
         }
     }
 
     /// Scans a process for the signature. This will scan the address range of
     /// the process given. If the signature is found, the address of the start
-    /// of the signature is returned.
+    /// of the signature is returned. Use [`scan_all`](Self::scan_all) if the
+    /// signature occurs multiple times and a later occurrence is needed.
     pub fn scan_process_range(
         &self,
         process: &Process,
-        (addr, len): (impl Into<Address>, u64),
+        range: (impl Into<Address>, u64),
     ) -> Option<Address> {
-        let mut addr: Address = Into::into(addr);
-        // TODO: Handle the case where a signature may be cut in half by a page
-        // boundary.
-        let overall_end = addr.value() + len;
-        let mut buf = [MaybeUninit::uninit(); 4 << 10];
-        while addr.value() < overall_end {
-            // We round up to the 4 KiB address boundary as that's a single
-            // page, which is safe to read either fully or not at all. We do
-            // this to do a single read rather than many small ones as the
-            // syscall overhead is a quite high.
-            let end = (addr.value() & !((4 << 10) - 1)) + (4 << 10).min(overall_end);
-            let len = end - addr.value();
-            let current_read_buf = &mut buf[..len as usize];
-            if let Ok(current_read_buf) = process.read_into_uninit_buf(addr, current_read_buf) {
-                if let Some(pos) = self.scan(current_read_buf) {
-                    return Some(addr.add(pos as u64));
-                }
+        self.scan_all(process, range).next()
+    }
+
+    /// Scans a process for all the occurrences of the signature within the
+    /// address range given. The addresses of the start of each occurrence are
+    /// yielded in ascending order. The range is scanned lazily, so only as
+    /// much of it is scanned as is needed for the occurrences that are
+    /// consumed. Occurrences may overlap each other.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn example(process: &asr::Process, range: (asr::Address, u64)) {
+    /// use asr::signature::Signature;
+    ///
+    /// static SIG: Signature<4> = Signature::new("12 34 ?? 78");
+    /// // The pattern is also used elsewhere, so we need the third occurrence.
+    /// let address = SIG.scan_all(process, range).nth(2);
+    /// # }
+    /// ```
+    pub fn scan_all<'a>(
+        &'a self,
+        process: &'a Process,
+        range: (impl Into<Address>, u64),
+    ) -> impl Iterator<Item = Address> + 'a {
+        let mut state = ScanState::new(range);
+        iter::from_fn(move || {
+            self.scan_until_found(
+                |address, buf| process.read_into_uninit_buf(address, buf).ok(),
+                &mut state,
+                u64::MAX,
+            )
+        })
+    }
+
+    /// Scans a process for the signature, but only scans up to `max_bytes`
+    /// bytes of the address range described by the [`ScanState`] per call.
+    /// This allows spreading the scan of a large address range across
+    /// multiple updates of the auto splitter, so a single update doesn't take
+    /// too long. The state keeps track of where the scan needs to continue.
+    /// Signatures that cross the boundary between two calls are still found.
+    ///
+    /// If the signature is found, [`ScanProgress::Found`] is returned with the
+    /// address of the start of the signature. Calling this again with the same
+    /// state continues scanning right after that address. Once the whole
+    /// range got scanned without finding the signature,
+    /// [`ScanProgress::NotFound`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example(process: &asr::Process, range: (asr::Address, u64)) {
+    /// use asr::signature::{ScanProgress, ScanState, Signature};
+    ///
+    /// static SIG: Signature<4> = Signature::new("12 34 ?? 78");
+    /// let mut state = ScanState::new(range);
+    /// let address = loop {
+    ///     match SIG.scan_process_chunked(process, &mut state, 1 << 20) {
+    ///         ScanProgress::InProgress => asr::future::next_tick().await,
+    ///         ScanProgress::Found(address) => break Some(address),
+    ///         ScanProgress::NotFound => break None,
+    ///     }
+    /// };
+    /// # }
+    /// ```
+    pub fn scan_process_chunked(
+        &self,
+        process: &Process,
+        state: &mut ScanState,
+        max_bytes: usize,
+    ) -> ScanProgress {
+        self.scan_chunk(
+            |address, buf| process.read_into_uninit_buf(address, buf).ok(),
+            state,
+            max_bytes as u64,
+        )
+    }
+
+    /// Keeps scanning the range described by the state, `budget` bytes at a
+    /// time, until either the signature is found or the whole range got
+    /// scanned.
+    fn scan_until_found(
+        &self,
+        mut read: impl for<'buf> FnMut(Address, &'buf mut [MaybeUninit<u8>]) -> Option<&'buf mut [u8]>,
+        state: &mut ScanState,
+        budget: u64,
+    ) -> Option<Address> {
+        loop {
+            match self.scan_chunk(&mut read, state, budget) {
+                ScanProgress::InProgress => continue,
+                ScanProgress::Found(address) => return Some(address),
+                ScanProgress::NotFound => return None,
+            }
+        }
+    }
+
+    /// Scans up to `max_bytes` bytes of the range described by the state,
+    /// reading the memory via `read`.
+    fn scan_chunk(
+        &self,
+        mut read: impl for<'buf> FnMut(Address, &'buf mut [MaybeUninit<u8>]) -> Option<&'buf mut [u8]>,
+        state: &mut ScanState,
+        max_bytes: u64,
+    ) -> ScanProgress {
+        // A page plus enough bytes to overlap with the next one, so signatures
+        // that cross the end of a page can still be found. Signatures are
+        // always shorter than 256 bytes.
+        let mut buf = [MaybeUninit::uninit(); (4 << 10) + 256];
+        let mut budget = max_bytes;
+
+        while budget > 0 {
+            if state.is_done() {
+                return ScanProgress::NotFound;
+            }
+            let start = state.next.value();
+
+            // Just like when scanning a range, we read at most a single page
+            // at a time, as a page is safe to read either fully or not at all.
+            let page_end = (start & !((4 << 10) - 1)) + (4 << 10);
+            let end = page_end
+                .min(state.end.value())
+                .min(start.saturating_add(budget));
+            let overlap_end = (end + (N as u64 - 1)).min(state.end.value());
+
+            // The next page may not be readable, in which case we try again
+            // without the overlap.
+            let scanned = match read(
+                Address::new(start),
+                &mut buf[..(overlap_end - start) as usize],
+            ) {
+                Some(buf) => self.scan_slice(buf),
+                None => read(Address::new(start), &mut buf[..(end - start) as usize])
+                    .and_then(|buf| self.scan_slice(buf)),
             };
-            addr = Address::new(end);
+
+            if let Some(pos) = scanned {
+                let found = Address::new(start + pos as u64);
+                state.next = found + 1;
+                return ScanProgress::Found(found);
+            }
+
+            budget -= end - start;
+            state.next = Address::new(end);
+        }
+
+        if state.is_done() {
+            ScanProgress::NotFound
+        } else {
+            ScanProgress::InProgress
         }
-        None
+    }
+
+    /// Scans a process for the signature, starting at the address given and
+    /// scanning `len` bytes from there. The start address is inclusive. This
+    /// is useful for scanning right after a known anchor, such as the start
+    /// of a function. If the signature is found, the address of the start of
+    /// the signature is returned.
+    #[inline]
+    pub fn scan_from(
+        &self,
+        process: &Process,
+        start: impl Into<Address>,
+        len: u64,
+    ) -> Option<Address> {
+        self.scan_process_range(process, (start, len))
+    }
+
+    /// Scans the code of a module (`exe` or `dll`) for the signature. Only the
+    /// sections of the module that contain executable code are scanned, so
+    /// the headers and data sections of the module can't cause any false
+    /// positives. If the signature is found, the address of the start of the
+    /// signature is returned.
+    pub fn scan_module_code(&self, process: &Process, module_name: &str) -> Option<Address> {
+        let module_address = process.get_module_address(module_name).ok()?;
+
+        pe::sections(process, module_address)
+            .filter(|section| section.is_executable())
+            .find_map(|section| self.scan_process_range(process, (section.address, section.size)))
+    }
+}
+
+/// The state of a scan that is spread across multiple calls to
+/// [`Signature::scan_process_chunked`]. It describes the address range that
+/// still needs to be scanned.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ScanState {
+    next: Address,
+    end: Address,
+}
+
+impl ScanState {
+    /// Creates the state for scanning the address range given, described by
+    /// its start address and length.
+    pub fn new((addr, len): (impl Into<Address>, u64)) -> Self {
+        let next: Address = addr.into();
+        Self {
+            next,
+            end: Address::new(next.value().saturating_add(len)),
+        }
+    }
+
+    /// Returns the address that the scan continues at.
+    pub const fn next(&self) -> Address {
+        self.next
+    }
+
+    /// Returns whether the whole address range got scanned.
+    pub fn is_done(&self) -> bool {
+        self.next >= self.end
+    }
+}
+
+/// The progress of a scan that is spread across multiple calls to
+/// [`Signature::scan_process_chunked`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScanProgress {
+    /// The scan used up its budget without finding the signature yet. The
+    /// scan needs to be continued.
+    InProgress,
+    /// The signature got found at the address given.
+    Found(Address),
+    /// The whole address range got scanned without finding the signature.
+    NotFound,
+}
+
+/// Scans for a signature with wildcards by using the skip table to jump
+/// ahead based on the last byte of the current window.
+fn scan_skip_table<const N: usize>(
+    haystack: &[u8],
+    needle: &[u8; N],
+    mask: &[u8; N],
+    skip_offsets: &[Offset; 256],
+) -> Option<usize> {
+    let mut current = 0;
+    let end = N - 1;
+    while let Some(scan) = strip_pod::<[u8; N]>(&mut &haystack[current..]) {
+        if matches(scan, needle, mask) {
+            return Some(current);
+        }
+        let offset = skip_offsets[scan[end] as usize];
+        current += offset as usize;
+    }
+    None
+}
+
+/// Scans for a signature with wildcards by searching for the byte at the
+/// `anchor` index of the signature first, which must not be a wildcard. This
+/// finds the same first occurrence as the skip table based search.
+///
+/// This doesn't use explicit SSE2 or AVX2 code with runtime feature
+/// detection. The search for the byte is done by `memchr`, which does that
+/// detection itself on x86-64 and uses `simd128` on WebAssembly when the
+/// target feature is enabled, with a scalar fallback otherwise. Auto
+/// splitters are compiled for WebAssembly, where there's no runtime
+/// detection to begin with.
+fn scan_prefiltered<const N: usize>(
+    haystack: &[u8],
+    needle: &[u8; N],
+    mask: &[u8; N],
+    anchor: usize,
+) -> Option<usize> {
+    let mut current = 0;
+    loop {
+        let pos = memchr::memchr(needle[anchor], haystack.get(current + anchor..)?)?;
+        let start = current + pos;
+        // If the signature doesn't fit at this candidate, it can't fit at
+        // any of the later ones either.
+        let scan = haystack.get(start..start + N)?.try_into().ok()?;
+        if matches(scan, needle, mask) {
+            return Some(start);
+        }
+        current = start + 1;
     }
 }
 
@@ -297,3 +664,123 @@ fn strip_pod<'a, T: AnyBitPattern>(cursor: &mut &'a [u8]) -> Option<&'a T> {
     *cursor = after;
     Some(bytemuck::from_bytes(before))
 }
+
+#[cfg(test)]
+mod tests {
+    use core::mem::MaybeUninit;
+
+    use super::{scan_prefiltered, scan_skip_table, ScanState, Signature};
+    use crate::Address;
+
+    const BASE: u64 = 0x10000;
+
+    fn read_from(
+        haystack: &[u8],
+    ) -> impl for<'buf> FnMut(Address, &'buf mut [MaybeUninit<u8>]) -> Option<&'buf mut [u8]> + '_
+    {
+        move |address, buf| {
+            let start = (address.value() - BASE) as usize;
+            let src = haystack.get(start..start + buf.len())?;
+            for (dst, &src) in buf.iter_mut().zip(src) {
+                dst.write(src);
+            }
+            // SAFETY: Every byte of the buffer got initialized above.
+            Some(unsafe { &mut *(buf as *mut [MaybeUninit<u8>] as *mut [u8]) })
+        }
+    }
+
+    #[test]
+    fn scan_continues_past_the_budget() {
+        static SIG: Signature<4> = Signature::new("12 34 ?? 78");
+        let mut haystack = [0; 3 << 12];
+        let len = haystack.len();
+        haystack[0x1800..0x1804].copy_from_slice(&[0x12, 0x34, 0x00, 0x78]);
+        haystack[len - 4..].copy_from_slice(&[0x12, 0x34, 0x56, 0x78]);
+
+        let mut state = ScanState::new((BASE, len as u64));
+        let mut read = read_from(&haystack);
+        assert_eq!(
+            SIG.scan_until_found(&mut read, &mut state, 0x1000),
+            Some(Address::new(BASE + 0x1800)),
+        );
+        assert_eq!(
+            SIG.scan_until_found(&mut read, &mut state, 0x1000),
+            Some(Address::new(BASE + len as u64 - 4)),
+        );
+        assert_eq!(SIG.scan_until_found(&mut read, &mut state, 0x1000), None);
+    }
+
+    /// A simple xorshift generator, so the haystacks are random, but the same
+    /// on every run.
+    fn next_random(state: &mut u32) -> u32 {
+        *state ^= *state << 13;
+        *state ^= *state >> 17;
+        *state ^= *state << 5;
+        *state
+    }
+
+    /// Checks that both searches find the same occurrences, by searching
+    /// again right after every occurrence found. Returns where the last
+    /// occurrence starts.
+    fn assert_same_matches<const N: usize>(sig: &Signature<N>, haystack: &[u8]) -> Option<usize> {
+        let Signature::Complex {
+            needle,
+            mask,
+            skip_offsets,
+        } = sig
+        else {
+            panic!("the signature needs to contain wildcards");
+        };
+        let anchor = mask.iter().position(|&mask| mask == 0xFF).unwrap();
+
+        let (mut start, mut last) = (0, None);
+        loop {
+            let rest = &haystack[start..];
+            let expected = scan_skip_table(rest, needle, mask, skip_offsets);
+            assert_eq!(scan_prefiltered(rest, needle, mask, anchor), expected);
+            let Some(pos) = expected else {
+                return last;
+            };
+            last = Some(start + pos);
+            start += pos + 1;
+        }
+    }
+
+    #[test]
+    fn prefiltered_scan_matches_skip_table_scan() {
+        static SIGS: [Signature<5>; 3] = [
+            Signature::new("?? 11 ?? 12 ??"),
+            Signature::new("?1 12 13 ?? 11"),
+            Signature::new("10 ?? 11 ?? 12"),
+        ];
+
+        let mut state = 0x2545_F491;
+        for sig in &SIGS {
+            let Signature::Complex { needle, mask, .. } = sig else {
+                panic!("the signature needs to contain wildcards");
+            };
+
+            for len in [0, 1, 4, 5, 6, 31, 64, 257, 1000] {
+                // A small alphabet makes partial and overlapping matches
+                // likely.
+                let mut haystack = [0; 1000];
+                let haystack = &mut haystack[..len];
+                for byte in haystack.iter_mut() {
+                    *byte = (next_random(&mut state) % 4) as u8 | 0x10;
+                }
+                assert_same_matches(sig, haystack);
+
+                // Place an occurrence at the very end, with random bytes
+                // where the signature has wildcards.
+                if let Some(start) = len.checked_sub(needle.len()) {
+                    for ((byte, &needle), &mask) in
+                        haystack[start..].iter_mut().zip(needle).zip(mask)
+                    {
+                        *byte = (next_random(&mut state) as u8 & !mask) | needle;
+                    }
+                    assert_eq!(assert_same_matches(sig, haystack), Some(start));
+                }
+            }
+        }
+    }
+}
@@ -6,7 +6,7 @@ use bytemuck::{Pod, Zeroable};
 
 pub use arrayvec::ArrayString;
 
-use crate::FromEndian;
+use crate::{Address, FromEndian, Process};
 
 /// A nul-terminated string that is stored in an array of a fixed size `N`. This
 /// can be read from a process's memory.
@@ -121,6 +121,25 @@ impl<const N: usize> ArrayWString<N> {
             && self.0.get(..chars.len()).is_some_and(|s| s == chars)
     }
 
+    /// Decodes the UTF-16 characters of the string up until (but excluding)
+    /// the nul-terminator. Invalid sequences, such as unpaired surrogates, are
+    /// replaced with [`char::REPLACEMENT_CHARACTER`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use asr::{string::ArrayWString, Address, Process};
+    /// # let process: Process = todo!();
+    /// # let address = Address::NULL;
+    /// let name = process.read::<ArrayWString<32>>(address).unwrap();
+    /// for c in name.chars() {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        decode_utf16_lossy(self.as_slice())
+    }
+
     /// Checks whether the string matches the given text. This dynamically
     /// re-encodes the passed in text to UTF-16, which is not as fast as
     /// [`matches`](Self::matches).
@@ -164,3 +183,40 @@ impl<const N: usize> FromEndian for ArrayWString<N> {
         Self(self.0.map(|x| x.from_le()))
     }
 }
+
+/// Decodes UTF-16 code units into characters, replacing invalid sequences,
+/// such as unpaired surrogates, with [`char::REPLACEMENT_CHARACTER`].
+pub(crate) fn decode_utf16_lossy(units: &[u16]) -> impl Iterator<Item = char> + '_ {
+    char::decode_utf16(units.iter().copied()).map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+}
+
+/// Checks whether the null-terminated string at the address given matches
+/// the string provided. Unlike reading the string into a fixed size buffer,
+/// this compares strings of any length, by reading only as many bytes as are
+/// needed for the comparison, in chunks.
+pub(crate) fn c_str_matches(process: &Process, address: Address, expected: &str) -> bool {
+    const CHUNK_SIZE: usize = 128;
+
+    let mut address = address;
+    let mut expected = expected.as_bytes();
+    let mut buf = [0; CHUNK_SIZE];
+
+    loop {
+        // We also need to read the null terminator after the expected bytes.
+        let len = (expected.len() + 1).min(CHUNK_SIZE);
+        let Ok(read) = process.read_partial(address, &mut buf[..len]) else {
+            return false;
+        };
+        let chunk = &buf[..read];
+
+        if read > expected.len() {
+            return chunk[..expected.len()] == *expected && chunk[expected.len()] == 0;
+        }
+        if *chunk != expected[..read] || read < len {
+            return false;
+        }
+
+        expected = &expected[read..];
+        address = address + read as u64;
+    }
+}
@@ -2,7 +2,9 @@
 
 use core::{mem, ops};
 
-use bytemuck::{bytes_of, NoUninit};
+use bytemuck::{bytes_of, CheckedBitPattern, NoUninit};
+
+use crate::{Address, PointerSize, Process};
 
 /// A watcher keeps a pair of values and allows you to track changes between
 /// them.
@@ -62,6 +64,39 @@ impl<T: Clone> Watcher<T> {
     }
 }
 
+impl<T: CheckedBitPattern> Watcher<T> {
+    /// Updates the watcher with a new value read from the process at the end
+    /// of the pointer path given, just like
+    /// [`Process::read_pointer_path`]. Returns the pair if the value could be
+    /// read. A path of `&[0]` reads the value at the address itself. If the
+    /// value is resolved some other way, such as through a `UnityPointer`,
+    /// pass the result to [`update`](Self::update) instead.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use asr::{watcher::Watcher, Address, PointerSize, Process};
+    /// # let process: Process = todo!();
+    /// # let module = Address::NULL;
+    /// let mut level = Watcher::<u32>::new();
+    ///
+    /// if let Some(level) = level.update_from_path(&process, module, PointerSize::Bit64, &[0x10, 0x48]) {
+    ///     if level.changed_from_to(&1, &2) {
+    ///         asr::timer::split();
+    ///     }
+    /// }
+    /// ```
+    pub fn update_from_path(
+        &mut self,
+        process: &Process,
+        address: impl Into<Address>,
+        pointer_size: PointerSize,
+        path: &[u64],
+    ) -> Option<&Pair<T>> {
+        self.update(process.read_pointer_path(address, pointer_size, path).ok())
+    }
+}
+
 /// A pair consisting of an old and a current value that can be used for
 /// tracking changes between them.
 #[derive(Copy, Clone, Default)]